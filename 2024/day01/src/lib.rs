@@ -0,0 +1,61 @@
+use aoc_common::{ParseError, non_empty_lines};
+use std::collections::HashMap;
+use std::iter::zip;
+
+pub type Input = (Vec<i64>, Vec<i64>);
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let mut a: Vec<i64> = Vec::new();
+    let mut b: Vec<i64> = Vec::new();
+
+    for line in non_empty_lines(input) {
+        let [left, right] = line.split_whitespace_exact()?;
+        a.push(left.parse()?);
+        b.push(right.parse()?);
+    }
+
+    Ok((a, b))
+}
+
+pub fn part_one((a, b): &Input) -> i64 {
+    let mut a: Vec<i64> = a.to_owned();
+    a.sort();
+    let mut b: Vec<i64> = b.to_owned();
+    b.sort();
+    let res: i64 = zip(a, b).map(|(a, b)| (a - b).abs()).sum();
+    res
+}
+
+pub fn part_two((a, b): &Input) -> i64 {
+    let mut counts: HashMap<i64, i64> = HashMap::new();
+    for element in b {
+        *counts.entry(*element).or_insert(0) += 1
+    }
+    a.iter()
+        .map(|x| counts.get(x).cloned().unwrap_or(0) * x)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 11);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 31);
+    }
+}
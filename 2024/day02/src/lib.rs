@@ -0,0 +1,68 @@
+use aoc_common::{ParseError, non_empty_lines};
+use itertools::Itertools;
+
+pub fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    non_empty_lines(input)
+        .map(|x| x.split_whitespace().map(|i| i.parse::<i32>()).collect())
+        .collect()
+}
+
+fn is_safe_report(row: Vec<i32>) -> bool {
+    if !row.is_sorted() && !row.iter().rev().is_sorted() {
+        return false;
+    }
+    for (left, right) in row.iter().tuple_windows() {
+        let diff = (left - right).abs();
+        if !(1..=3).contains(&diff) {
+            return false;
+        }
+    }
+    true
+}
+
+pub fn part_one(grid: &[Vec<i32>]) -> usize {
+    grid.iter()
+        .filter(|row| is_safe_report(row.to_vec()))
+        .count()
+}
+
+pub fn part_two(grid: &[Vec<i32>]) -> usize {
+    grid.iter()
+        .filter(|row| {
+            is_safe_report(row.to_vec())
+                || row.iter().enumerate().any(|(i, _)| {
+                    is_safe_report(
+                        row[..i]
+                            .iter()
+                            .chain(&row[(i + 1)..])
+                            .cloned()
+                            .collect::<Vec<_>>(),
+                    )
+                })
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 2);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 4);
+    }
+}
@@ -0,0 +1,223 @@
+use aoc_common::ParseError;
+use std::ops::Range;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(i64, i64),
+    Do,
+    Dont,
+}
+
+/// How the arguments of a call are written.
+struct Arguments {
+    max_digits: usize,
+    signed: bool,
+}
+
+/// A call that the lexer recognises, such as `mul(2,4)`.
+struct Signature {
+    name: &'static str,
+    arity: usize,
+    arguments: Arguments,
+    build: fn(&[i64]) -> Instruction,
+}
+
+/// Calls without arguments don't care how they would be written.
+const NO_ARGUMENTS: Arguments = Arguments {
+    max_digits: 0,
+    signed: false,
+};
+
+const SIGNATURES: [Signature; 3] = [
+    Signature {
+        name: "mul",
+        arity: 2,
+        arguments: Arguments {
+            max_digits: 3,
+            signed: false,
+        },
+        build: |args| Instruction::Mul(args[0], args[1]),
+    },
+    Signature {
+        name: "do",
+        arity: 0,
+        arguments: NO_ARGUMENTS,
+        build: |_| Instruction::Do,
+    },
+    Signature {
+        name: "don't",
+        arity: 0,
+        arguments: NO_ARGUMENTS,
+        build: |_| Instruction::Dont,
+    },
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    Instruction(Instruction),
+    /// Bytes of the input that aren't part of any well-formed call.
+    Garbage(Range<usize>),
+}
+
+/// Picks the well-formed calls out of corrupted text.
+pub struct Lexer<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Lexer { text, pos: 0 }
+    }
+
+    /// The length of the call at the start of `rest`, and what it does.
+    fn call(rest: &str) -> Option<(usize, Instruction)> {
+        SIGNATURES.iter().find_map(|signature| {
+            let mut remaining = rest.strip_prefix(signature.name)?.strip_prefix('(')?;
+            let mut args = Vec::with_capacity(signature.arity);
+            for i in 0..signature.arity {
+                if i > 0 {
+                    remaining = remaining.strip_prefix(',')?;
+                }
+                let (arg, after) = Self::argument(remaining, &signature.arguments)?;
+                args.push(arg);
+                remaining = after;
+            }
+            remaining = remaining.strip_prefix(')')?;
+            Some((rest.len() - remaining.len(), (signature.build)(&args)))
+        })
+    }
+
+    fn argument<'t>(text: &'t str, arguments: &Arguments) -> Option<(i64, &'t str)> {
+        let sign = match text.strip_prefix('-') {
+            Some(_) if arguments.signed => 1,
+            _ => 0,
+        };
+        let digits = text[sign..].bytes().take_while(u8::is_ascii_digit).count();
+        if !(1..=arguments.max_digits).contains(&digits) {
+            return None;
+        }
+        let (arg, rest) = text.split_at(sign + digits);
+        Some((arg.parse().ok()?, rest))
+    }
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        let start = self.pos;
+        while let Some(c) = self.text[self.pos..].chars().next() {
+            if let Some((len, instruction)) = Self::call(&self.text[self.pos..]) {
+                if self.pos > start {
+                    return Some(Token::Garbage(start..self.pos));
+                }
+                self.pos += len;
+                return Some(Token::Instruction(instruction));
+            }
+            self.pos += c.len_utf8();
+        }
+        (self.pos > start).then_some(Token::Garbage(start..self.pos))
+    }
+}
+
+/// Any text is a valid (if corrupted) program.
+pub fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    Ok(Lexer::new(input)
+        .filter_map(|token| match token {
+            Token::Instruction(instruction) => Some(instruction),
+            Token::Garbage(_) => None,
+        })
+        .collect())
+}
+
+/// Runs a program, optionally letting `do()` and `don't()` switch the
+/// multiplications on and off.
+struct Interpreter {
+    conditionals: bool,
+    enabled: bool,
+    total: i64,
+}
+
+impl Interpreter {
+    fn new(conditionals: bool) -> Self {
+        Interpreter {
+            conditionals,
+            enabled: true,
+            total: 0,
+        }
+    }
+
+    fn execute(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::Mul(a, b) if self.enabled => self.total += a * b,
+            Instruction::Mul(..) => {}
+            Instruction::Do => self.enabled = true,
+            Instruction::Dont => self.enabled = !self.conditionals,
+        }
+    }
+
+    fn run(mut self, program: &[Instruction]) -> i64 {
+        for &instruction in program {
+            self.execute(instruction);
+        }
+        self.total
+    }
+}
+
+pub fn part_one(program: &[Instruction]) -> i64 {
+    Interpreter::new(false).run(program)
+}
+
+pub fn part_two(program: &[Instruction]) -> i64 {
+    Interpreter::new(true).run(program)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART_ONE_EXAMPLE: &str = "\
+xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
+";
+
+    const PART_TWO_EXAMPLE: &str = "\
+xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+";
+
+    #[test]
+    fn lexer_reports_garbage() {
+        let tokens: Vec<Token> = Lexer::new("xmul(2,4)%mul(1234,5)don't()").collect();
+        assert_eq!(
+            tokens,
+            [
+                Token::Garbage(0..1),
+                Token::Instruction(Instruction::Mul(2, 4)),
+                Token::Garbage(9..21),
+                Token::Instruction(Instruction::Dont),
+            ]
+        );
+    }
+
+    #[test]
+    fn arguments_follow_their_signature() {
+        let signed = Arguments {
+            max_digits: 4,
+            signed: true,
+        };
+        assert_eq!(Lexer::argument("-1234)", &signed), Some((-1234, ")")));
+        assert_eq!(Lexer::argument("-1)", &SIGNATURES[0].arguments), None);
+        assert_eq!(Lexer::argument("1234)", &SIGNATURES[0].arguments), None);
+        assert_eq!(Lexer::call("mul(-1,2)"), None);
+    }
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(PART_ONE_EXAMPLE).unwrap()), 161);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(PART_TWO_EXAMPLE).unwrap()), 48);
+    }
+}
@@ -0,0 +1,96 @@
+use aoc_common::{Grid, ParseError, Point};
+
+pub fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse_from_str(input)
+}
+
+pub fn part_one(grid: &Grid<char>) -> i32 {
+    let directions = [
+        Point::new(1, 0),
+        Point::new(-1, 0),
+        Point::new(0, 1),
+        Point::new(0, -1),
+        Point::new(1, 1),
+        Point::new(-1, -1),
+        Point::new(1, -1),
+        Point::new(-1, 1),
+    ];
+
+    let mut res = 0;
+
+    for start in grid.points() {
+        for direction in directions {
+            let word: String = (0..4)
+                .filter_map(|step| grid.get(start + direction * step))
+                .collect();
+            if word == "XMAS" {
+                res += 1
+            }
+        }
+    }
+
+    res
+}
+
+fn is_direction_valid(grid: &Grid<char>, middle: Point, direction: Point) -> bool {
+    let word: String = (-1..=1)
+        .map(|step| grid[middle + direction * step])
+        .collect();
+
+    word == "MAS" || word == "SAM"
+}
+
+pub fn part_two(grid: &Grid<char>) -> i32 {
+    let (rows, cols) = (grid.rows() as i32, grid.cols() as i32);
+
+    // The two perpendicular directions for the X
+    let directions = [Point::new(-1, 1), Point::new(1, 1)];
+    let mut res = 0;
+    for middle in grid.points() {
+        if middle.row == 0 || middle.row == rows - 1 || middle.col == 0 || middle.col == cols - 1 {
+            continue;
+        }
+        if directions
+            .iter()
+            .all(|&direction| is_direction_valid(grid, middle, direction))
+        {
+            res += 1
+        }
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 18);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 9);
+    }
+
+    #[test]
+    fn part_two_searches_every_column_of_wide_grids() {
+        let grid = parse_input("M.S.M\n.A.A.\nM.S.M\n").unwrap();
+        assert_eq!(part_two(&grid), 2);
+    }
+}
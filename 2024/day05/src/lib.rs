@@ -0,0 +1,160 @@
+use aoc_common::{
+    ParseError, split_sections,
+    toposort::{Cycle, Dag, TieBreak},
+};
+use std::collections::HashMap;
+
+type Page = i32;
+
+#[derive(Default)]
+pub struct PageOrderBuilder {
+    ordered_edges: Vec<(Page, Page)>,
+}
+
+impl PageOrderBuilder {
+    pub fn add_ordered_edge(
+        &mut self,
+        (independent_page, dependent_page): (Page, Page),
+    ) -> &mut Self {
+        self.ordered_edges.push((independent_page, dependent_page));
+        self
+    }
+
+    pub fn build(self) -> PageOrder {
+        PageOrder {
+            ordered_edges: self.ordered_edges,
+        }
+    }
+}
+
+pub struct PageOrder {
+    ordered_edges: Vec<(Page, Page)>,
+}
+
+impl PageOrder {
+    pub fn validate_order(&self, order: &[Page]) -> bool {
+        let mut idx_in_order: HashMap<Page, usize> = HashMap::new();
+        for (idx, &page) in order.iter().enumerate() {
+            idx_in_order.insert(page, idx);
+        }
+
+        self.ordered_edges
+            .iter()
+            .all(|(independent_page, dependent_page)| {
+                match (
+                    idx_in_order.get(independent_page),
+                    idx_in_order.get(dependent_page),
+                ) {
+                    (Some(u), Some(v)) => u < v,
+                    _ => true,
+                }
+            })
+    }
+
+    /// The rules as a whole may be cyclic, but those between the pages of
+    /// a single update must not be.
+    pub fn topological_reorder(&self, pages: &[Page]) -> Result<Vec<Page>, Cycle<Page>> {
+        let mut subgraph = Dag::new();
+        for &page in pages {
+            subgraph.add_node(page);
+        }
+        for &(independent_page, dependent_page) in self.ordered_edges.iter() {
+            if pages.contains(&independent_page) && pages.contains(&dependent_page) {
+                subgraph.add_edge(independent_page, dependent_page);
+            }
+        }
+        subgraph.kahn(TieBreak::Insertion)
+    }
+}
+
+pub type Input = (PageOrder, Vec<Vec<Page>>);
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let [rules, updates] = split_sections(input)?;
+    let mut page_order_builder = PageOrderBuilder::default();
+    for line in rules {
+        let (independent_page, dependent_page) = line.split_once("|")?;
+        page_order_builder.add_ordered_edge((independent_page.parse()?, dependent_page.parse()?));
+    }
+    let mut orderings: Vec<Vec<Page>> = Vec::new();
+    for line in updates {
+        let ordering = line
+            .split(",")
+            .map(|token| token.parse::<Page>())
+            .collect::<Result<_, _>>()?;
+        orderings.push(ordering);
+    }
+    Ok((page_order_builder.build(), orderings))
+}
+
+pub fn part_one((page_order, orderings): &Input) -> i64 {
+    orderings
+        .iter()
+        .filter(|ordering| page_order.validate_order(ordering))
+        .map(|ordering| ordering[ordering.len() / 2] as i64)
+        .sum()
+}
+
+pub fn part_two((page_order, orderings): &Input) -> Result<i64, Cycle<Page>> {
+    orderings
+        .iter()
+        .filter(|ordering| !page_order.validate_order(ordering))
+        .map(|ordering| {
+            let ordering = page_order.topological_reorder(ordering)?;
+            Ok(ordering[ordering.len() / 2] as i64)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 143);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), Ok(123));
+    }
+
+    #[test]
+    fn cyclic_rules_within_an_update_are_errors() {
+        let input = parse_input("1|2\n2|3\n3|1\n4|1\n\n1,4\n1,2,3\n").unwrap();
+        assert_eq!(part_one(&input), 0);
+        assert_eq!(part_two(&input), Err(Cycle(vec![1, 2, 3])));
+    }
+}
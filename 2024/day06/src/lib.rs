@@ -0,0 +1,147 @@
+use aoc_common::{Direction, Grid, ParseError, Point};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct GuardPosition {
+    coordinates: Point,
+    direction: Direction,
+}
+
+enum StepResult {
+    Success(GuardPosition),
+    FoundObstacle,
+    GridEscaped,
+}
+
+#[derive(Clone)]
+pub struct GridWalk {
+    pos: GuardPosition,
+    grid: Grid<char>,
+}
+
+pub fn parse_input(input: &str) -> Result<GridWalk, ParseError> {
+    let grid = Grid::parse_from_str(input)?;
+
+    let pos = grid.iter().find_map(|(coordinates, &c)| {
+        let direction = Direction::from_arrow(c)?;
+        Some(GuardPosition {
+            coordinates,
+            direction,
+        })
+    });
+    match pos {
+        Some(pos) => Ok(GridWalk { pos, grid }),
+        None => Err(ParseError::end_of_input(
+            input,
+            "a guard (`^`, `>`, `v` or `<`)",
+        )),
+    }
+}
+
+impl GridWalk {
+    fn step_forward(&self) -> StepResult {
+        let pos = self.pos;
+        let coordinates = pos.coordinates + pos.direction;
+        let Some(&cell) = self.grid.get(coordinates) else {
+            return StepResult::GridEscaped;
+        };
+        let configuration = GuardPosition {
+            direction: pos.direction,
+            coordinates,
+        };
+        match cell {
+            '#' => StepResult::FoundObstacle,
+            _ => StepResult::Success(configuration),
+        }
+    }
+
+    fn turn(&mut self) {
+        self.pos.direction = self.pos.direction.turn_right();
+    }
+}
+
+pub fn part_one(grid_walk: &GridWalk) -> usize {
+    let mut grid_walk = grid_walk.clone();
+    let mut visited_coordinates: HashSet<Point> = HashSet::new();
+    loop {
+        visited_coordinates.insert(grid_walk.pos.coordinates);
+        match grid_walk.step_forward() {
+            StepResult::GridEscaped => {
+                break;
+            }
+            StepResult::FoundObstacle => grid_walk.turn(),
+            StepResult::Success(new_position) => {
+                grid_walk.pos = new_position;
+            }
+        }
+    }
+    visited_coordinates.len()
+}
+
+fn causes_infinite_loop(mut grid_walk: GridWalk) -> bool {
+    let mut visited_guard_positions: HashSet<GuardPosition> = HashSet::new();
+    loop {
+        if visited_guard_positions.contains(&grid_walk.pos) {
+            return true;
+        }
+        visited_guard_positions.insert(grid_walk.pos);
+        match grid_walk.step_forward() {
+            StepResult::GridEscaped => {
+                return false;
+            }
+            StepResult::FoundObstacle => grid_walk.turn(),
+            StepResult::Success(new_position) => {
+                grid_walk.pos = new_position;
+            }
+        }
+    }
+}
+
+pub fn part_two(grid_walk: &GridWalk) -> usize {
+    let mut jhs = Vec::new();
+    for candidate in grid_walk.grid.points() {
+        if candidate == grid_walk.pos.coordinates {
+            continue;
+        }
+        let mut grid_walk = grid_walk.clone();
+        jhs.push(std::thread::spawn(move || {
+            grid_walk.grid[candidate] = '#';
+            causes_infinite_loop(grid_walk)
+        }));
+    }
+    let mut res = 0;
+    for jh in jhs {
+        if jh.join().unwrap() {
+            res += 1
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 41);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 6);
+    }
+}
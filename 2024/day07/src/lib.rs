@@ -0,0 +1,101 @@
+use aoc_common::{ParseError, non_empty_lines};
+use std::collections::BTreeSet;
+
+pub struct Equation {
+    operands: Vec<u64>,
+    result: u64,
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Equation>, ParseError> {
+    let lines = non_empty_lines(input);
+
+    let mut equations = Vec::new();
+    for line in lines {
+        let (result, operands) = line.split_once(":")?;
+        let result: u64 = result.parse()?;
+        let operands: Vec<u64> = operands
+            .split_whitespace()
+            .map(|str| str.parse())
+            .collect::<Result<_, _>>()?;
+        equations.push(Equation { result, operands });
+    }
+
+    Ok(equations)
+}
+
+fn part_one_next_candidates(value_so_far: u64, operand: u64) -> Vec<u64> {
+    vec![value_so_far * operand, value_so_far + operand]
+}
+
+fn part_two_next_candidates(value_so_far: u64, operand: u64) -> Vec<u64> {
+    vec![
+        value_so_far * operand,
+        value_so_far + operand,
+        (value_so_far.to_string() + &operand.to_string())
+            .parse()
+            .unwrap(),
+    ]
+}
+
+fn check_possible(
+    equation: &Equation,
+    generate_next_candidate: impl Fn(u64, u64) -> Vec<u64>,
+) -> bool {
+    let mut possible_values: BTreeSet<u64> = BTreeSet::new();
+    possible_values.insert(equation.operands[0]);
+    for operand in &equation.operands[1..] {
+        let mut new_possible_values: BTreeSet<u64> = BTreeSet::new();
+        for possible_value in possible_values.iter() {
+            for next_candidate in generate_next_candidate(*possible_value, *operand) {
+                if next_candidate <= equation.result {
+                    new_possible_values.insert(next_candidate);
+                }
+            }
+        }
+        possible_values = new_possible_values;
+    }
+    possible_values.contains(&equation.result)
+}
+
+pub fn part_one(equations: &[Equation]) -> u64 {
+    equations
+        .iter()
+        .filter(|equation| check_possible(equation, part_one_next_candidates))
+        .map(|equation| equation.result)
+        .sum()
+}
+
+pub fn part_two(equations: &[Equation]) -> u64 {
+    equations
+        .iter()
+        .filter(|equation| check_possible(equation, part_two_next_candidates))
+        .map(|equation| equation.result)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 3749);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 11387);
+    }
+}
@@ -0,0 +1,95 @@
+use aoc_common::{Grid, ParseError, Point};
+use gcd::Gcd;
+use itertools::Itertools;
+use std::cmp::max;
+use std::collections::{HashMap, HashSet};
+
+pub fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse_from_str(input)
+}
+
+fn coordinates_by_signals(grid: &Grid<char>) -> HashMap<char, Vec<Point>> {
+    let mut coordinates_for_signal: HashMap<char, Vec<Point>> = HashMap::new();
+    for (point, &signal) in grid.iter() {
+        if signal == '.' {
+            continue;
+        }
+        coordinates_for_signal
+            .entry(signal)
+            .or_default()
+            .push(point);
+    }
+    coordinates_for_signal
+}
+
+pub fn part_one(grid: &Grid<char>) -> usize {
+    let mut unique_antinodes: HashSet<Point> = HashSet::new();
+    let coordinates_for_signal = coordinates_by_signals(grid);
+    for (_, coordinates) in coordinates_for_signal.into_iter() {
+        for (&c1, &c2) in coordinates.iter().cartesian_product(coordinates.iter()) {
+            if c1 >= c2 {
+                continue;
+            }
+            let delta = c2 - c1;
+            for candidate in [c1 - delta, c2 + delta] {
+                if grid.contains(candidate) {
+                    unique_antinodes.insert(candidate);
+                }
+            }
+        }
+    }
+    unique_antinodes.len()
+}
+
+pub fn part_two(grid: &Grid<char>) -> usize {
+    let mut unique_antinodes: HashSet<Point> = HashSet::new();
+    let coordinates_for_signal = coordinates_by_signals(grid);
+    for (_, coordinates) in coordinates_for_signal.into_iter() {
+        for (&c1, &c2) in coordinates.iter().cartesian_product(coordinates.iter()) {
+            if c1 >= c2 {
+                continue;
+            }
+            let delta = c2 - c1;
+            let gcd = delta.row.unsigned_abs().gcd(delta.col.unsigned_abs()) as i32;
+            let step = Point::new(delta.row / gcd, delta.col / gcd);
+            let dim = max(grid.rows(), grid.cols()) as i32;
+            let found_antinodes = (-dim..=dim)
+                .map(|multiple| c1 + step * multiple)
+                .filter(|&antinode| grid.contains(antinode));
+            for antinode in found_antinodes {
+                unique_antinodes.insert(antinode);
+            }
+        }
+    }
+    unique_antinodes.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 14);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 34);
+    }
+}
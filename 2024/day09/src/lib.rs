@@ -0,0 +1,144 @@
+use aoc_common::{ParseError, lines};
+use itertools::Itertools;
+use std::cmp::min;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum BlockType {
+    Occupied(u64), // File index
+    Free,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Block {
+    block_type: BlockType,
+    block_size: u64,
+    starting_index: u64,
+}
+
+pub fn parse_input(input: &str) -> Result<String, ParseError> {
+    let line = lines(input)
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "a disk map"))?;
+    if let Some((_, span)) = line.chars().find(|(c, _)| !c.is_ascii_digit()) {
+        return Err(span.error("a digit"));
+    }
+    Ok(line.text().to_string())
+}
+
+pub fn part_one(disk_map: &str) -> u64 {
+    let mut free_blocks: Vec<u64> = Vec::new();
+    let mut occupied_blocks: Vec<(u64, u64)> = Vec::new();
+    let mut seen_blocks_count: u64 = 0;
+    let mut checksum: u64 = 0;
+    for (i, block_size) in disk_map.chars().enumerate() {
+        let block_size: u64 = block_size
+            .to_digit(10)
+            .expect("parse_input only allows digits") as u64;
+        let found_range = seen_blocks_count..seen_blocks_count + block_size;
+        if i % 2 == 0 {
+            let file_index = i / 2;
+            occupied_blocks.extend(
+                found_range
+                    .map(|position| (position, file_index as u64))
+                    .collect::<Vec<_>>(),
+            );
+        } else {
+            free_blocks.extend(found_range);
+        }
+        seen_blocks_count += block_size;
+    }
+
+    let occupied_back_iter = occupied_blocks.iter().rev();
+    for zip_element in occupied_back_iter.zip_longest(free_blocks) {
+        match zip_element {
+            itertools::EitherOrBoth::Left(&(occupied_block, occupied_block_file_index)) => {
+                checksum += occupied_block * occupied_block_file_index;
+            }
+            itertools::EitherOrBoth::Both(
+                &(occupied_block, occupied_block_file_index),
+                free_block,
+            ) => checksum += min(free_block, occupied_block) * occupied_block_file_index,
+            _ => {}
+        }
+    }
+    checksum
+}
+
+/// The sum of `start..start + len`, which is zero for files of no blocks.
+fn range_sum(start: u64, len: u64) -> u64 {
+    start * len + len * len.saturating_sub(1) / 2
+}
+
+pub fn part_two(disk_map: &str) -> u64 {
+    let mut blocks: Vec<Block> = Vec::new();
+    let mut seen_indices = 0;
+    for (i, block_size) in disk_map.chars().enumerate() {
+        let block_size = block_size
+            .to_digit(10)
+            .expect("parse_input only allows digits") as u64;
+        blocks.push(Block {
+            block_type: if i % 2 == 0 {
+                BlockType::Occupied((i / 2) as u64)
+            } else {
+                BlockType::Free
+            },
+            block_size,
+            starting_index: seen_indices,
+        });
+        seen_indices += block_size;
+    }
+    let mut checksum: u64 = 0;
+    let files: Vec<(u64, Block)> = blocks
+        .iter()
+        .rev()
+        .filter_map(|&block| match block.block_type {
+            BlockType::Occupied(file_index) => Some((file_index, block)),
+            BlockType::Free => None,
+        })
+        .collect();
+    for (file_index, occupied_block) in files {
+        if let Some(first_suitable_block) = blocks
+            .iter_mut()
+            .filter(|block| block.block_type == BlockType::Free)
+            .find(|block| block.block_size >= occupied_block.block_size)
+            && first_suitable_block.starting_index < occupied_block.starting_index
+        {
+            checksum += file_index
+                * range_sum(
+                    first_suitable_block.starting_index,
+                    occupied_block.block_size,
+                );
+            first_suitable_block.block_size -= occupied_block.block_size;
+            first_suitable_block.starting_index += occupied_block.block_size;
+        } else {
+            checksum +=
+                file_index * range_sum(occupied_block.starting_index, occupied_block.block_size);
+        }
+    }
+    checksum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2333133121414131402
+";
+
+    #[test]
+    fn empty_files_add_nothing() {
+        assert_eq!(part_one(&parse_input("012\n").unwrap()), 1);
+        assert_eq!(part_two(&parse_input("012\n").unwrap()), 3);
+    }
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 1928);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 2858);
+    }
+}
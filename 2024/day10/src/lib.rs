@@ -0,0 +1,60 @@
+use aoc_common::{Grid, ParseError, search::bfs};
+
+pub fn parse_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse_with(input, |c, span| {
+        c.to_digit(10).ok_or_else(|| span.error("a digit"))
+    })
+}
+
+fn calculate_score_and_rating(grid: &Grid<u32>) -> (i32, i32) {
+    let mut sum_zero_scores: i32 = 0;
+    let mut sum_zero_ratings: i32 = 0;
+    for trailhead in grid.points().filter(|&point| grid[point] == 0) {
+        // Every step climbs by one, so every trail is a shortest path.
+        let search = bfs([trailhead], |&point| {
+            grid.neighbours(point)
+                .filter(move |&neighbour| grid[neighbour] == grid[point] + 1)
+        });
+        for (&point, _) in search.distances() {
+            if grid[point] == 9 {
+                sum_zero_scores += 1;
+                sum_zero_ratings += search.path_count(&point) as i32;
+            }
+        }
+    }
+    (sum_zero_scores, sum_zero_ratings)
+}
+
+pub fn part_one(grid: &Grid<u32>) -> i32 {
+    calculate_score_and_rating(grid).0
+}
+
+pub fn part_two(grid: &Grid<u32>) -> i32 {
+    calculate_score_and_rating(grid).1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 36);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 81);
+    }
+}
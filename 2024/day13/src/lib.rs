@@ -0,0 +1,258 @@
+use aoc_common::{ParseError, Span, non_empty_lines};
+use itertools::Itertools;
+use regex::Regex;
+use std::{cmp::min, sync::LazyLock};
+
+#[derive(Debug, Clone, Copy)]
+struct Prize {
+    x: i64,
+    y: i64,
+}
+
+impl Prize {
+    fn parse(line: Span) -> Result<Self, ParseError> {
+        static RE: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"Prize: X=(\d+), Y=(\d+)").unwrap());
+        if let Some(captures) = RE.captures(line.text()) {
+            let x: i64 = line.slice(&captures[1]).parse()?;
+            let y: i64 = line.slice(&captures[2]).parse()?;
+            Ok(Prize { x, y })
+        } else {
+            Err(line.error("`Prize: X=<x>, Y=<y>`"))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Button {
+    dx: i64,
+    dy: i64,
+    cost_per_press: i64,
+}
+
+impl Button {
+    fn parse(line: Span) -> Result<Self, ParseError> {
+        static RE: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"Button (A|B): X\+(\d+), Y\+(\d+)").unwrap());
+        if let Some(captures) = RE.captures(line.text()) {
+            let button_type = &captures[1];
+            let dx: i64 = line.slice(&captures[2]).parse()?;
+            let dy: i64 = line.slice(&captures[3]).parse()?;
+            Ok(Button {
+                dx,
+                dy,
+                cost_per_press: match button_type {
+                    "A" => 3,
+                    _ => 1,
+                },
+            })
+        } else {
+            Err(line.error("`Button <A|B>: X+<dx>, Y+<dy>`"))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Machine {
+    buttons: (Button, Button),
+    prize: Prize,
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Machine>, ParseError> {
+    let mut lines = non_empty_lines(input);
+    let mut machines = Vec::new();
+    while let Some(a) = lines.next() {
+        let b = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(input, "a second button"))?;
+        let c = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(input, "a prize"))?;
+        machines.push(Machine {
+            buttons: (Button::parse(a)?, Button::parse(b)?),
+            prize: Prize::parse(c)?,
+        });
+    }
+    Ok(machines)
+}
+
+fn calculate_cost(machines: &[Machine]) -> i64 {
+    const NUM_TIMES: i64 = 100;
+    let mut res = 0;
+    for machine in machines {
+        let mut minimum_cost: Option<i64> = None;
+        for (a_presses, b_presses) in (0..=NUM_TIMES).cartesian_product(0..=NUM_TIMES) {
+            let cost = a_presses * machine.buttons.0.cost_per_press
+                + b_presses * machine.buttons.1.cost_per_press;
+            let x = machine.buttons.0.dx * a_presses + machine.buttons.1.dx * b_presses;
+            let y = machine.buttons.0.dy * a_presses + machine.buttons.1.dy * b_presses;
+            if x == machine.prize.x && y == machine.prize.y {
+                minimum_cost = match minimum_cost {
+                    None => Some(cost),
+                    Some(previous_cost) => Some(min(previous_cost, cost)),
+                }
+            }
+        }
+        res += minimum_cost.unwrap_or_default()
+    }
+    res
+}
+
+/// `(g, s, t)` such that `s * a + t * b == g`, the gcd of `a` and `b`.
+fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, s, t) = extended_gcd(b, a % b);
+        (g, t, s - (a / b) * t)
+    }
+}
+
+/// The cheapest way to win when both buttons move the claw along the same
+/// line, so the prize alone doesn't pin down the presses.
+fn cheapest_collinear(machine: &Machine) -> Option<i64> {
+    let (a, b) = machine.buttons;
+    let prize = machine.prize;
+    // On an axis the buttons move along, the presses `x` and `y` satisfy
+    // `u * x + v * y == w`. Only the ends of the solutions can be cheapest,
+    // since the cost changes steadily from one solution to the next.
+    let (u, v, w) = if a.dx != 0 || b.dx != 0 {
+        (a.dx, b.dx, prize.x)
+    } else {
+        (a.dy, b.dy, prize.y)
+    };
+    let candidates = match (u, v) {
+        (0, 0) => vec![(0, 0)],
+        (0, _) => vec![(0, w / v)],
+        (_, 0) => vec![(w / u, 0)],
+        _ => {
+            let (g, s, _) = extended_gcd(u, v);
+            if w % g != 0 {
+                return None;
+            }
+            let period = v / g;
+            let first = (s * (w / g)).rem_euclid(period);
+            let last = first + (w / u - first).div_euclid(period) * period;
+            [first, last]
+                .into_iter()
+                .map(|x| (x, (w - u * x) / v))
+                .collect()
+        }
+    };
+    candidates
+        .into_iter()
+        .filter(|&(x, y)| {
+            x >= 0 && y >= 0 && x * a.dx + y * b.dx == prize.x && x * a.dy + y * b.dy == prize.y
+        })
+        .map(|(x, y)| x * a.cost_per_press + y * b.cost_per_press)
+        .min()
+}
+
+fn calculate_cost_smart(machines: &[Machine]) -> i64 {
+    let mut res = 0;
+    for machine in machines {
+        let mut minimum_cost: Option<i64> = None;
+        /*
+            x * a + y * c == e
+            x * b + y * d == f
+
+            x = Dx / D = (ed - cf) / (ad - bc)
+            y = Dy / D = (af - be) / (ad - bc)
+        */
+        let (a, b) = (machine.buttons.0.dx, machine.buttons.0.dy);
+        let (c, d) = (machine.buttons.1.dx, machine.buttons.1.dy);
+        let (e, f) = (machine.prize.x, machine.prize.y);
+
+        let det = a * d - b * c;
+        if det != 0 {
+            if (e * d - c * f) % det == 0 && (a * f - b * e) % det == 0 {
+                let x = (e * d - c * f) / det;
+                let y = (a * f - b * e) / det;
+                if x >= 0 && y >= 0 {
+                    minimum_cost = Some(3 * x + y);
+                }
+            }
+        } else {
+            minimum_cost = cheapest_collinear(machine);
+        }
+        res += minimum_cost.unwrap_or_default()
+    }
+    res
+}
+
+pub fn part_one(machines: &[Machine]) -> i64 {
+    calculate_cost(machines)
+}
+
+pub fn part_two(machines: &[Machine]) -> i64 {
+    let mut machines = machines.to_vec();
+    for machine in machines.iter_mut() {
+        machine.prize.x += 10000000000000;
+        machine.prize.y += 10000000000000;
+    }
+    calculate_cost_smart(&machines)
+}
+
+/*
+x * (a, b) -> 3x
+y * (c, d) -> y
+(e, f)
+
+x * a + y * c == e
+x * b + y * d == f
+
+minimize 3*x + y
+*/
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 480);
+    }
+
+    #[test]
+    fn collinear_buttons_are_solved() {
+        let machine = |text: &str| parse_input(text).unwrap();
+        // Pressing A once is cheaper than pressing B ten more times.
+        let cheap_a = machine("Button A: X+30, Y+10\nButton B: X+3, Y+1\nPrize: X=33, Y=11\n");
+        assert_eq!(calculate_cost_smart(&cheap_a), 4);
+        // Here A only covers twice the distance for three times the cost.
+        let cheap_b = machine("Button A: X+4, Y+2\nButton B: X+2, Y+1\nPrize: X=10, Y=5\n");
+        assert_eq!(calculate_cost_smart(&cheap_b), 5);
+        let off_line = machine("Button A: X+30, Y+10\nButton B: X+3, Y+1\nPrize: X=33, Y=12\n");
+        assert_eq!(calculate_cost_smart(&off_line), 0);
+        let odd = machine("Button A: X+4, Y+2\nButton B: X+6, Y+3\nPrize: X=9, Y=4\n");
+        assert_eq!(calculate_cost_smart(&odd), 0);
+        let idle = machine("Button A: X+0, Y+0\nButton B: X+2, Y+1\nPrize: X=6, Y=3\n");
+        assert_eq!(calculate_cost_smart(&idle), 3);
+        for machines in [&cheap_a, &cheap_b] {
+            assert_eq!(calculate_cost_smart(machines), calculate_cost(machines));
+        }
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 875318608908);
+    }
+}
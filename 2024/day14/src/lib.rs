@@ -0,0 +1,268 @@
+use aoc_common::{ParseError, Point, non_empty_lines};
+use serde::Deserialize;
+use std::{
+    collections::{BTreeSet, HashMap, VecDeque},
+    fmt,
+    sync::LazyLock,
+};
+
+use regex::Regex;
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// The room is `dims.0` tiles wide and `dims.1` tiles tall.
+    pub dims: (i64, i64),
+    /// How many seconds part one waits for.
+    pub elapsed_time: i64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            dims: (101, 103),
+            elapsed_time: 100,
+        }
+    }
+}
+
+impl Params {
+    /// The room's dimensions, which must be positive and small enough to
+    /// draw.
+    fn dims(&self) -> Result<(i64, i64), RobotError> {
+        let fits = |len: i64| len > 0 && i32::try_from(len).is_ok();
+        match self.dims {
+            (width, height) if fits(width) && fits(height) => Ok(self.dims),
+            dims => Err(RobotError::BadDims(dims)),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Grid {
+    robots: Vec<Robot>,
+}
+
+struct Robot {
+    position: (i64, i64),
+    velocity: (i64, i64),
+}
+
+#[derive(PartialEq, Eq, Hash, Debug)]
+enum Quadrant {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl Robot {
+    fn future_position(&self, elapsed_time: i64, dims: (i64, i64)) -> (i64, i64) {
+        let x = self.position.0 + self.velocity.0 * elapsed_time;
+        let y = self.position.1 + self.velocity.1 * elapsed_time;
+
+        let (x, y) = (x % dims.0, y % dims.1);
+        let (x, y) = (
+            match x < 0 {
+                true => x + dims.0,
+                false => x,
+            },
+            match y < 0 {
+                true => y + dims.1,
+                false => y,
+            },
+        );
+        (x, y)
+    }
+}
+
+static ROBOT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"p=(-?\d+),(-?\d+) v=(-?\d+),(-?\d+)").unwrap());
+
+pub fn parse_input(input: &str) -> Result<Grid, ParseError> {
+    let robots: Vec<Robot> = non_empty_lines(input)
+        .map(|line| {
+            if let Some(captures) = ROBOT.captures(line.text()) {
+                let (x, y): (i64, i64) = (
+                    line.slice(&captures[1]).parse()?,
+                    line.slice(&captures[2]).parse()?,
+                );
+                let (dx, dy): (i64, i64) = (
+                    line.slice(&captures[3]).parse()?,
+                    line.slice(&captures[4]).parse()?,
+                );
+                Ok(Robot {
+                    position: (x, y),
+                    velocity: (dx, dy),
+                })
+            } else {
+                Err(line.error("`p=<x>,<y> v=<dx>,<dy>`"))
+            }
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Grid { robots })
+}
+
+pub fn draw(grid: &Grid, params: &Params, elapsed_time: i64) -> Result<String, RobotError> {
+    let dims = params.dims()?;
+    let mut picture = aoc_common::Grid::new(dims.1 as usize, dims.0 as usize, '.');
+    for robot in grid.robots.iter() {
+        let (x, y) = robot.future_position(elapsed_time, dims);
+        picture[Point::new(y as i32, x as i32)] = '#';
+    }
+    Ok(picture.to_string())
+}
+
+fn check(grid: &Grid, dims: (i64, i64), elapsed_time: i64) -> bool {
+    let mut positions: BTreeSet<(i64, i64)> = BTreeSet::new();
+    for robot in grid.robots.iter() {
+        let (x, y) = robot.future_position(elapsed_time, dims);
+        positions.insert((x, y));
+    }
+    let directions = [(-1, 0), (0, -1), (1, 0), (0, 1)];
+    while !positions.is_empty() {
+        let mut component_size = 0;
+        let mut component_queue: VecDeque<(i64, i64)> = VecDeque::new();
+        component_queue.push_back(*positions.last().unwrap());
+        positions.remove(component_queue.back().unwrap());
+        loop {
+            if component_queue.is_empty() {
+                break;
+            }
+            let (x, y) = component_queue.pop_back().unwrap();
+            component_size += 1;
+            for (dx, dy) in directions {
+                let next_cell = (x + dx, y + dy);
+                if !positions.contains(&next_cell) {
+                    continue;
+                }
+                component_queue.push_back(next_cell);
+                positions.remove(&next_cell);
+            }
+        }
+        if component_size >= 30 {
+            return true;
+        }
+    }
+    false
+}
+
+pub fn part_one(grid: &Grid, params: &Params) -> Result<i64, RobotError> {
+    let dims = params.dims()?;
+    let mut quadrant_counts: HashMap<Quadrant, i64> = HashMap::from([
+        (Quadrant::TopLeft, 0),
+        (Quadrant::TopRight, 0),
+        (Quadrant::BottomLeft, 0),
+        (Quadrant::BottomRight, 0),
+    ]);
+    for robot in grid.robots.iter() {
+        let (rx, ry) = robot.future_position(params.elapsed_time, dims);
+        if 2 * rx == (dims.0 - 1) || 2 * ry == (dims.1 - 1) {
+            continue;
+        }
+        let quadrant = match (rx < (dims.0 - 1) / 2, ry < (dims.1 - 1) / 2) {
+            (true, true) => Quadrant::TopLeft,
+            (false, true) => Quadrant::TopRight,
+            (true, false) => Quadrant::BottomLeft,
+            (false, false) => Quadrant::BottomRight,
+        };
+        *quadrant_counts.entry(quadrant).or_insert(0) += 1;
+    }
+    Ok(quadrant_counts.values().product())
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RobotError {
+    /// The room must be at least one tile wide and tall.
+    BadDims((i64, i64)),
+    /// No cluster of robots formed before their positions started repeating.
+    NoTree { period: i64 },
+}
+
+impl fmt::Display for RobotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RobotError::BadDims((width, height)) => write!(
+                f,
+                "dims must be positive 32-bit sizes, found [{width}, {height}]"
+            ),
+            RobotError::NoTree { period } => write!(
+                f,
+                "no cluster of robots forms before their positions repeat after {period} seconds"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RobotError {}
+
+pub fn part_two(grid: &Grid, params: &Params) -> Result<i64, RobotError> {
+    let dims = params.dims()?;
+    // Every robot is back where it started after `dims.0 * dims.1` seconds.
+    let period = dims.0 * dims.1;
+    (1..=period)
+        .find(|&elapsed_time| check(grid, dims, elapsed_time))
+        .ok_or(RobotError::NoTree { period })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+
+    const PARAMS: Params = Params {
+        dims: (11, 7),
+        elapsed_time: 100,
+    };
+
+    #[test]
+    fn robot_wraps_around_the_example_room() {
+        let robot = Robot {
+            position: (2, 4),
+            velocity: (2, -3),
+        };
+        assert_eq!(robot.future_position(5, PARAMS.dims), (1, 3));
+    }
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap(), &PARAMS), Ok(12));
+    }
+
+    #[test]
+    fn bad_dims_are_errors() {
+        let grid = parse_input(EXAMPLE).unwrap();
+        for dims in [(0, 7), (11, -7), (1 << 40, 7)] {
+            let params = Params {
+                dims,
+                elapsed_time: 100,
+            };
+            assert_eq!(part_one(&grid, &params), Err(RobotError::BadDims(dims)));
+            assert_eq!(part_two(&grid, &params), Err(RobotError::BadDims(dims)));
+            assert_eq!(draw(&grid, &params, 1), Err(RobotError::BadDims(dims)));
+        }
+    }
+
+    #[test]
+    fn part_two_gives_up_after_a_full_period() {
+        let grid = parse_input(EXAMPLE).unwrap();
+        assert_eq!(
+            part_two(&grid, &PARAMS),
+            Err(RobotError::NoTree { period: 77 })
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use aoc_common::{Direction, Grid, ParseError, Point, split_sections};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+#[derive(Debug, Clone)]
+pub struct GridWalk {
+    grid: Grid<char>,
+    walk: Vec<Direction>,
+    position: Point,
+}
+
+impl GridWalk {
+    fn scaled(&self) -> GridWalk {
+        let rows = (0..self.grid.rows())
+            .map(|row| {
+                self.grid
+                    .row(row)
+                    .iter()
+                    .flat_map(|c| match c {
+                        '@' => ['@', '.'],
+                        '.' => ['.', '.'],
+                        'O' => ['[', ']'],
+                        '#' => ['#', '#'],
+                        _ => unreachable!("parse_input only allows `@`, `.`, `O` and `#`"),
+                    })
+                    .collect()
+            })
+            .collect();
+        GridWalk {
+            grid: Grid::from_rows(rows),
+            walk: self.walk.clone(),
+            position: Point::new(self.position.row, self.position.col * 2),
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<GridWalk, ParseError> {
+    let [map, moves] = split_sections(input)?;
+    // With a single robot in a walled-in room, every move stays on the map
+    // and only runs into walls, boxes or floor.
+    let mut robots = 0;
+    for (row, line) in map.iter().enumerate() {
+        let last_col = line.text().len() - 1;
+        for (col, (c, span)) in line.chars().enumerate() {
+            let on_border = row == 0 || row == map.len() - 1 || col == 0 || col == last_col;
+            if on_border && c != '#' {
+                return Err(span.error("a wall (`#`) around the map"));
+            }
+            if c == '@' {
+                robots += 1;
+                if robots > 1 {
+                    return Err(span.error("a single robot"));
+                }
+            }
+        }
+    }
+
+    let grid = Grid::parse_lines_with(map, |c, span| match c {
+        '@' | '.' | 'O' | '#' => Ok(c),
+        _ => Err(span.error("one of `@`, `.`, `O` or `#`")),
+    })?;
+
+    let position = grid
+        .find(&'@')
+        .ok_or_else(|| ParseError::end_of_input(input, "a robot (`@`)"))?;
+    let mut walk: Vec<Direction> = Vec::new();
+    for line in moves {
+        for (c, span) in line.chars() {
+            let step = Direction::from_arrow(c)
+                .ok_or_else(|| span.error("one of `>`, `^`, `<` or `v`"))?;
+            walk.push(step);
+        }
+    }
+
+    Ok(GridWalk {
+        walk,
+        grid,
+        position,
+    })
+}
+
+/// The sum of the GPS coordinates of every `box_char`.
+fn gps_sum(grid: &Grid<char>, box_char: char) -> i64 {
+    grid.iter()
+        .filter(|&(_, &c)| c == box_char)
+        .map(|(point, _)| (point.row * 100 + point.col) as i64)
+        .sum()
+}
+
+pub fn part_one(grid_walk: &GridWalk) -> i64 {
+    let mut grid_walk = grid_walk.clone();
+    let grid = &mut grid_walk.grid;
+    for &step in grid_walk.walk.iter() {
+        let current = grid_walk.position;
+        let next = current + step;
+        let mut moved = false;
+        match grid[next] {
+            '.' => {
+                grid[next] = '@';
+                grid[current] = '.';
+                moved = true;
+            }
+            '#' => {
+                moved = false;
+            }
+            'O' => {
+                let far = (0..)
+                    .map(|distance| current + step.delta() * distance)
+                    .find(|&cell| grid[cell] == '#' || grid[cell] == '.')
+                    .expect("the map is walled in");
+                if grid[far] == '.' {
+                    grid[current] = '.';
+                    grid[next] = '@';
+                    grid[far] = 'O';
+                    moved = true;
+                }
+            }
+            _ => unreachable!("there is a single robot"),
+        }
+        if moved {
+            grid_walk.position = next;
+        }
+    }
+
+    gps_sum(grid, 'O')
+}
+
+pub fn part_two(grid_walk: &GridWalk) -> i64 {
+    let mut grid_walk = grid_walk.scaled();
+    let grid = &mut grid_walk.grid;
+    for &step in grid_walk.walk.iter() {
+        let current = grid_walk.position;
+        let next = current + step;
+        match grid[next] {
+            '.' => {
+                grid_walk.position = next;
+                grid[next] = '@';
+                grid[current] = '.';
+            }
+            '#' => {}
+            '[' | ']' => {
+                let mut queue: VecDeque<Point> = Default::default();
+                let mut added: HashSet<Point> = Default::default();
+
+                queue.push_back(current);
+                added.insert(current);
+                let mut is_blocked = false;
+                while let Some(cell) = queue.pop_front() {
+                    let next_cell = cell + step;
+                    let c = grid[cell];
+                    if let Some(other_half) = match c {
+                        '[' => Some(cell + Direction::Right),
+                        ']' => Some(cell + Direction::Left),
+                        _ => None,
+                    } && !added.contains(&other_half)
+                    {
+                        queue.push_front(other_half);
+                        added.insert(other_half);
+                    }
+                    match grid[next_cell] {
+                        '#' => {
+                            is_blocked = true;
+                            break;
+                        }
+                        '.' => {
+                            continue;
+                        }
+                        _ => {}
+                    }
+                    if !added.contains(&next_cell) {
+                        queue.push_back(next_cell);
+                        added.insert(next_cell);
+                    }
+                }
+                if is_blocked {
+                    continue;
+                }
+                let mut value_cache: HashMap<Point, char> = Default::default();
+                for &cell in added.iter() {
+                    value_cache.insert(cell, grid[cell]);
+                    grid[cell] = '.';
+                }
+                for &cell in added.iter() {
+                    grid[cell + step] = value_cache[&cell];
+                }
+                grid_walk.position = next;
+            }
+            _ => unreachable!("there is a single robot"),
+        }
+    }
+    gps_sum(grid, '[')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL_EXAMPLE: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+
+    const LARGE_EXAMPLE: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+";
+
+    #[test]
+    fn part_one_small_example() {
+        assert_eq!(part_one(&parse_input(SMALL_EXAMPLE).unwrap()), 2028);
+    }
+
+    #[test]
+    fn part_one_large_example() {
+        assert_eq!(part_one(&parse_input(LARGE_EXAMPLE).unwrap()), 10092);
+    }
+
+    #[test]
+    fn maps_need_walls_and_a_single_robot() {
+        let err = |input: &str| {
+            let err = parse_input(input).err().unwrap();
+            (err.line, err.column, err.expected)
+        };
+        let open = "####\n#@O.\n####\n\n>>>\n";
+        assert_eq!(err(open), (2, 4, "a wall (`#`) around the map".into()));
+        let crowded = "#####\n#@.@#\n#####\n\n>\n";
+        assert_eq!(err(crowded), (2, 4, "a single robot".into()));
+    }
+
+    #[test]
+    fn part_two_large_example() {
+        assert_eq!(part_two(&parse_input(LARGE_EXAMPLE).unwrap()), 9021);
+    }
+}
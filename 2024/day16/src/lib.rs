@@ -0,0 +1,147 @@
+use aoc_common::{
+    Direction, Grid, ParseError, Point,
+    search::{Search, dijkstra},
+};
+use std::{collections::HashSet, fmt};
+
+type Cell = Point;
+
+pub struct ShortestPaths {
+    grid: Grid<char>,
+    start: Cell,
+    end: Cell,
+}
+
+impl ShortestPaths {
+    /// Walking forwards costs one point, turning in place costs a thousand.
+    fn calculate(&self) -> Search<(Cell, Direction)> {
+        dijkstra([(self.start, Direction::Right)], |&(cell, direction)| {
+            let forward = cell + direction;
+            [
+                (self.grid.get(forward).is_some_and(|&tile| tile != '#'))
+                    .then_some(((forward, direction), 1)),
+                Some(((cell, direction.turn_left()), 1000)),
+                Some(((cell, direction.turn_right()), 1000)),
+            ]
+            .into_iter()
+            .flatten()
+        })
+    }
+
+    fn end_states(&self) -> impl Iterator<Item = (Cell, Direction)> + '_ {
+        Direction::ALL
+            .map(|direction| (self.end, direction))
+            .into_iter()
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<ShortestPaths, ParseError> {
+    let grid = Grid::parse_from_str(input)?;
+
+    let start = grid.find(&'S');
+    let end = grid.find(&'E');
+
+    Ok(ShortestPaths {
+        start: start.ok_or_else(|| ParseError::end_of_input(input, "a start tile (`S`)"))?,
+        end: end.ok_or_else(|| ParseError::end_of_input(input, "an end tile (`E`)"))?,
+        grid,
+    })
+}
+
+/// The walls cut the end tile off from the start.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Unreachable;
+
+impl fmt::Display for Unreachable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the end tile can't be reached from the start")
+    }
+}
+
+impl std::error::Error for Unreachable {}
+
+pub fn part_one(grid: &ShortestPaths) -> Result<u64, Unreachable> {
+    let search = grid.calculate();
+    grid.end_states()
+        .filter_map(|state| search.distance(&state))
+        .min()
+        .ok_or(Unreachable)
+}
+
+pub fn part_two(grid: &ShortestPaths) -> Result<usize, Unreachable> {
+    let search = grid.calculate();
+    let tiles: HashSet<Cell> = search
+        .on_shortest_paths(grid.end_states())
+        .into_iter()
+        .map(|(cell, _)| cell)
+        .collect();
+    if tiles.is_empty() {
+        return Err(Unreachable);
+    }
+    Ok(tiles.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIRST_EXAMPLE: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+
+    const SECOND_EXAMPLE: &str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+
+    #[test]
+    fn part_one_examples() {
+        assert_eq!(part_one(&parse_input(FIRST_EXAMPLE).unwrap()), Ok(7036));
+        assert_eq!(part_one(&parse_input(SECOND_EXAMPLE).unwrap()), Ok(11048));
+    }
+
+    #[test]
+    fn part_two_examples() {
+        assert_eq!(part_two(&parse_input(FIRST_EXAMPLE).unwrap()), Ok(45));
+        assert_eq!(part_two(&parse_input(SECOND_EXAMPLE).unwrap()), Ok(64));
+    }
+
+    #[test]
+    fn open_edges_and_walled_off_ends() {
+        let unwalled = parse_input("###\n#SE\n").unwrap();
+        assert_eq!(part_one(&unwalled), Ok(1));
+        assert_eq!(part_two(&unwalled), Ok(2));
+        let walled_off = parse_input("#####\n#S#E#\n#####\n").unwrap();
+        assert_eq!(part_one(&walled_off), Err(Unreachable));
+        assert_eq!(part_two(&walled_off), Err(Unreachable));
+    }
+}
@@ -0,0 +1,663 @@
+use aoc_common::{ParseError, split_sections};
+use itertools::Itertools;
+use std::{collections::BTreeSet, fmt};
+
+/// How many instructions [`VM::run`] executes before giving up on a program
+/// that doesn't halt.
+pub const STEP_BUDGET: usize = 1 << 20;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+enum OperandType {
+    Literal,
+    Combo,
+    Ignored,
+}
+
+impl Opcode {
+    const ALL: [Opcode; 8] = [
+        Opcode::Adv,
+        Opcode::Bxl,
+        Opcode::Bst,
+        Opcode::Jnz,
+        Opcode::Bxc,
+        Opcode::Out,
+        Opcode::Bdv,
+        Opcode::Cdv,
+    ];
+
+    pub fn from_u8(value: u8) -> Option<Self> {
+        Self::ALL.get(value as usize).copied()
+    }
+
+    fn operand_type(self) -> OperandType {
+        match self {
+            Opcode::Bxl | Opcode::Jnz => OperandType::Literal,
+            Opcode::Bxc => OperandType::Ignored,
+            Opcode::Adv | Opcode::Bst | Opcode::Out | Opcode::Bdv | Opcode::Cdv => {
+                OperandType::Combo
+            }
+        }
+    }
+}
+
+impl fmt::Display for Opcode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mnemonic = format!("{self:?}").to_lowercase();
+        write!(f, "{mnemonic}")
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub operand: u8,
+}
+
+impl Instruction {
+    /// The operand as the instruction reads it: a number, or a register.
+    fn operand_name(&self) -> String {
+        match (self.opcode.operand_type(), self.operand) {
+            (OperandType::Combo, 4) => "A".to_string(),
+            (OperandType::Combo, 5) => "B".to_string(),
+            (OperandType::Combo, 6) => "C".to_string(),
+            (OperandType::Combo, 7) => "<reserved>".to_string(),
+            _ => self.operand.to_string(),
+        }
+    }
+
+    /// What the instruction does, written as pseudocode.
+    pub fn pseudocode(&self) -> String {
+        let x = self.operand_name();
+        match self.opcode {
+            Opcode::Adv => format!("A = A >> {x}"),
+            Opcode::Bxl => format!("B = B ^ {x}"),
+            Opcode::Bst => format!("B = {x} % 8"),
+            Opcode::Jnz => format!("if A != 0: goto {}", self.operand / 2),
+            Opcode::Bxc => "B = B ^ C".to_string(),
+            Opcode::Out => format!("out({x} % 8)"),
+            Opcode::Bdv => format!("B = A >> {x}"),
+            Opcode::Cdv => format!("C = A >> {x}"),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.opcode, self.operand)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Register {
+    A,
+    B,
+    C,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Registers {
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+}
+
+impl Registers {
+    pub fn get(&self, register: Register) -> u64 {
+        match register {
+            Register::A => self.a,
+            Register::B => self.b,
+            Register::C => self.c,
+        }
+    }
+}
+
+impl fmt::Display for Registers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "A={} B={} C={}", self.a, self.b, self.c)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VmError {
+    /// A combo operand of 7, which valid programs don't use.
+    ReservedOperand { ip: usize },
+    /// A jump into the middle of an instruction.
+    MisalignedJump { ip: usize, target: u8 },
+    /// The program ran for longer than its budget, and probably never halts.
+    OutOfSteps { budget: usize },
+    /// There is no instruction left to run.
+    Halted { ip: usize },
+}
+
+impl fmt::Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VmError::ReservedOperand { ip } => {
+                write!(f, "instruction {ip} uses the reserved combo operand 7")
+            }
+            VmError::MisalignedJump { ip, target } => {
+                write!(f, "instruction {ip} jumps to odd address {target}")
+            }
+            VmError::OutOfSteps { budget } => {
+                write!(f, "program didn't halt within {budget} instructions")
+            }
+            VmError::Halted { ip } => write!(f, "program halted at {ip}, past its end"),
+        }
+    }
+}
+
+impl std::error::Error for VmError {}
+
+#[derive(Debug, Clone)]
+pub struct VM {
+    registers: Registers,
+
+    instructions: Vec<Instruction>,
+    raw_instructions: Vec<u8>,
+    ip: usize,
+}
+
+impl VM {
+    pub fn registers(&self) -> Registers {
+        self.registers
+    }
+
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.ip >= self.instructions.len()
+    }
+
+    /// Starts over from the first instruction with the given registers.
+    pub fn reset(&mut self, registers: Registers) {
+        self.registers = registers;
+        self.ip = 0;
+    }
+
+    fn operand_value(&self, instruction: &Instruction) -> Result<u64, VmError> {
+        match instruction.opcode.operand_type() {
+            OperandType::Combo => match instruction.operand {
+                0..=3 => Ok(instruction.operand as u64),
+                4 => Ok(self.registers.a),
+                5 => Ok(self.registers.b),
+                6 => Ok(self.registers.c),
+                _ => Err(VmError::ReservedOperand { ip: self.ip }),
+            },
+            OperandType::Literal | OperandType::Ignored => Ok(instruction.operand.into()),
+        }
+    }
+
+    /// Executes the instruction at the instruction pointer, returning what it
+    /// printed, if anything.
+    pub fn step(&mut self) -> Result<Option<u8>, VmError> {
+        let &instruction = self
+            .instructions
+            .get(self.ip)
+            .ok_or(VmError::Halted { ip: self.ip })?;
+        let operand_value = self.operand_value(&instruction)?;
+        // Shifting by 64 or more clears every bit.
+        let shift = |value: u64| {
+            u32::try_from(operand_value)
+                .ok()
+                .and_then(|by| value.checked_shr(by))
+                .unwrap_or(0)
+        };
+        let registers = &mut self.registers;
+
+        let mut output: Option<u8> = None;
+        match instruction.opcode {
+            Opcode::Adv => registers.a = shift(registers.a),
+            Opcode::Bxl => registers.b ^= operand_value,
+            Opcode::Bst => registers.b = operand_value % 8,
+            Opcode::Jnz => {
+                if registers.a != 0 {
+                    if operand_value % 2 == 1 {
+                        return Err(VmError::MisalignedJump {
+                            ip: self.ip,
+                            target: instruction.operand,
+                        });
+                    }
+                    self.ip = (operand_value as usize) / 2;
+                    return Ok(None);
+                }
+            }
+            Opcode::Bxc => registers.b ^= registers.c,
+            Opcode::Out => output = Some((operand_value % 8) as u8),
+            Opcode::Bdv => registers.b = shift(registers.a),
+            Opcode::Cdv => registers.c = shift(registers.a),
+        }
+        self.ip += 1;
+        Ok(output)
+    }
+
+    /// Runs until the program halts, executing at most `budget` instructions.
+    pub fn run(&mut self, budget: usize) -> Result<Vec<u8>, VmError> {
+        let mut output: Vec<u8> = Vec::new();
+        for _ in 0..budget {
+            if self.is_halted() {
+                return Ok(output);
+            }
+            if let Some(value) = self.step()? {
+                output.push(value);
+            }
+        }
+        if self.is_halted() {
+            return Ok(output);
+        }
+        Err(VmError::OutOfSteps { budget })
+    }
+
+    /// One line of pseudocode per instruction, prefixed by its address.
+    pub fn disassemble(&self) -> String {
+        self.instructions
+            .iter()
+            .enumerate()
+            .map(|(ip, instruction)| format!("{ip}: {}\n", instruction.pseudocode()))
+            .collect()
+    }
+}
+
+/// One executed instruction, as recorded by the [`Debugger`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceEntry {
+    pub ip: usize,
+    pub instruction: Instruction,
+    /// The registers before the instruction ran.
+    pub registers: Registers,
+    pub output: Option<u8>,
+}
+
+/// Why [`Debugger::resume`] handed control back.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stop {
+    /// The next instruction to run is at a breakpoint.
+    Breakpoint(usize),
+    /// A watched register changed.
+    Watch {
+        register: Register,
+        old: u64,
+        new: u64,
+    },
+    Halted,
+}
+
+/// Runs a [`VM`] an instruction at a time, recording a trace.
+pub struct Debugger {
+    vm: VM,
+    breakpoints: BTreeSet<usize>,
+    watches: Vec<Register>,
+    trace: Vec<TraceEntry>,
+    output: Vec<u8>,
+    budget: usize,
+}
+
+impl Debugger {
+    pub fn new(vm: VM) -> Self {
+        Debugger {
+            vm,
+            breakpoints: BTreeSet::new(),
+            watches: Vec::new(),
+            trace: Vec::new(),
+            output: Vec::new(),
+            budget: STEP_BUDGET,
+        }
+    }
+
+    pub fn vm(&self) -> &VM {
+        &self.vm
+    }
+
+    pub fn set_budget(&mut self, budget: usize) {
+        self.budget = budget;
+    }
+
+    pub fn add_breakpoint(&mut self, ip: usize) {
+        self.breakpoints.insert(ip);
+    }
+
+    pub fn remove_breakpoint(&mut self, ip: usize) {
+        self.breakpoints.remove(&ip);
+    }
+
+    pub fn watch(&mut self, register: Register) {
+        if !self.watches.contains(&register) {
+            self.watches.push(register);
+        }
+    }
+
+    pub fn trace(&self) -> &[TraceEntry] {
+        &self.trace
+    }
+
+    pub fn output(&self) -> &[u8] {
+        &self.output
+    }
+
+    /// Runs a single instruction, reporting a change to a watched register.
+    pub fn step(&mut self) -> Result<Option<Stop>, VmError> {
+        if self.vm.is_halted() {
+            return Ok(Some(Stop::Halted));
+        }
+        if self.trace.len() >= self.budget {
+            return Err(VmError::OutOfSteps {
+                budget: self.budget,
+            });
+        }
+        let (ip, registers) = (self.vm.ip, self.vm.registers);
+        let instruction = self.vm.instructions[ip];
+        let output = self.vm.step()?;
+        self.output.extend(output);
+        self.trace.push(TraceEntry {
+            ip,
+            instruction,
+            registers,
+            output,
+        });
+        let changed = self.watches.iter().find_map(|&register| {
+            let (old, new) = (registers.get(register), self.vm.registers.get(register));
+            (old != new).then_some(Stop::Watch { register, old, new })
+        });
+        Ok(changed)
+    }
+
+    /// Runs until a breakpoint, a watched register changes, or the program
+    /// halts. A breakpoint at the current instruction doesn't stop it again.
+    pub fn resume(&mut self) -> Result<Stop, VmError> {
+        loop {
+            if let Some(stop) = self.step()? {
+                return Ok(stop);
+            }
+            if self.vm.is_halted() {
+                return Ok(Stop::Halted);
+            }
+            if self.breakpoints.contains(&self.vm.ip) {
+                return Ok(Stop::Breakpoint(self.vm.ip));
+            }
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<VM, ParseError> {
+    let [registers, program] = split_sections(input)?;
+    let mut vm = VM {
+        registers: Registers::default(),
+        instructions: Vec::new(),
+        raw_instructions: Vec::new(),
+        ip: 0,
+    };
+    let mut previous_line = registers[0];
+    let mut registers = registers.into_iter();
+    for (name, register) in [
+        ("A", &mut vm.registers.a),
+        ("B", &mut vm.registers.b),
+        ("C", &mut vm.registers.c),
+    ] {
+        let prefix = format!("Register {name}: ");
+        let line = registers
+            .next()
+            .ok_or_else(|| previous_line.missing_after(format!("`{prefix}`")))?;
+        *register = line.strip_prefix(&prefix)?.parse()?;
+        previous_line = line;
+    }
+    if let Some(extra) = registers.next() {
+        return Err(extra.error("a blank line"));
+    }
+    if let Some(extra) = program.get(1) {
+        return Err(extra.error("end of input"));
+    }
+    let program = program[0].strip_prefix("Program: ")?;
+    let mut raw_instructions: Vec<u8> = Vec::new();
+    for token in program.split(",") {
+        match token.parse()? {
+            value @ 0..=7 => raw_instructions.push(value),
+            _ => return Err(token.error("a 3-bit number")),
+        }
+    }
+    if !raw_instructions.len().is_multiple_of(2) {
+        return Err(program.end().error("an operand"));
+    }
+    vm.raw_instructions = raw_instructions.clone();
+    let instructions = raw_instructions
+        .into_iter()
+        .tuples()
+        .map(|(opcode, operand)| Instruction {
+            opcode: Opcode::from_u8(opcode).unwrap(),
+            operand,
+        })
+        .collect();
+    vm.instructions = instructions;
+    Ok(vm)
+}
+
+pub fn part_one(vm: &VM) -> Result<String, VmError> {
+    Ok(vm.clone().run(STEP_BUDGET)?.into_iter().join(","))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuineError {
+    /// The program doesn't shift A right by a constant once per loop, so its
+    /// output can't be matched a chunk of A at a time.
+    UnsupportedShape,
+    /// No value of A makes the program print itself.
+    NoSolution,
+}
+
+impl fmt::Display for QuineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QuineError::UnsupportedShape => {
+                write!(f, "program doesn't shift A by a constant amount")
+            }
+            QuineError::NoSolution => write!(f, "no value of A makes the program print itself"),
+        }
+    }
+}
+
+impl std::error::Error for QuineError {}
+
+impl VM {
+    /// How many bits of A the program drops per loop, from its one `adv`.
+    fn shift(&self) -> Result<u32, QuineError> {
+        let mut shifts = self
+            .instructions
+            .iter()
+            .filter(|instruction| instruction.opcode == Opcode::Adv);
+        match (shifts.next(), shifts.next()) {
+            (Some(&Instruction { operand, .. }), None) if (1..=3).contains(&operand) => {
+                Ok(operand.into())
+            }
+            _ => Err(QuineError::UnsupportedShape),
+        }
+    }
+
+    /// What the program prints when started with `a` in register A.
+    fn output_for(&self, a: u64) -> Option<Vec<u8>> {
+        let mut vm = self.clone();
+        vm.reset(Registers {
+            a,
+            ..self.registers
+        });
+        vm.run(STEP_BUDGET).ok()
+    }
+}
+
+/// The smallest value of A that makes the program print itself.
+///
+/// Each loop prints one value and then shifts A right, so the last values
+/// printed only depend on the top chunks of A. A is built from its most
+/// significant chunk down, keeping the candidates whose output matches as
+/// long a suffix of the program as they have chunks.
+pub fn find_quine(vm: &VM) -> Result<u64, QuineError> {
+    let shift = vm.shift()?;
+    let expected = &vm.raw_instructions;
+    let mut candidates = vec![(0, 0u64)];
+    while let Some((chunks, a)) = candidates.pop() {
+        if chunks == expected.len() {
+            return Ok(a);
+        }
+        let suffix = &expected[expected.len() - chunks - 1..];
+        // Pushed in reverse, so that smaller chunks are tried first.
+        for chunk in (0..1u64 << shift).rev() {
+            let Some(next) = a.checked_shl(shift).map(|a| a | chunk) else {
+                continue;
+            };
+            if vm.output_for(next).as_deref() == Some(suffix) {
+                candidates.push((chunks + 1, next));
+            }
+        }
+    }
+    Err(QuineError::NoSolution)
+}
+
+pub fn part_two(vm: &VM) -> Result<u64, QuineError> {
+    find_quine(vm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART_ONE_EXAMPLE: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+
+    const PART_TWO_EXAMPLE: &str = "\
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(
+            part_one(&parse_input(PART_ONE_EXAMPLE).unwrap()),
+            Ok("4,6,3,5,6,3,5,2,1,0".to_string())
+        );
+    }
+
+    /// A program shaped like the real inputs.
+    const QUINE_SHAPED: &str = "\
+Register A: 0
+Register B: 0
+Register C: 0
+
+Program: 2,4,1,7,7,5,1,7,0,3,4,0,5,5,3,0
+";
+
+    #[test]
+    fn disassembles_into_pseudocode() {
+        let vm = parse_input(QUINE_SHAPED).unwrap();
+        assert_eq!(
+            vm.disassemble(),
+            "\
+0: B = A % 8
+1: B = B ^ 7
+2: C = A >> B
+3: B = B ^ 7
+4: A = A >> 3
+5: B = B ^ C
+6: out(B % 8)
+7: if A != 0: goto 0
+"
+        );
+    }
+
+    #[test]
+    fn debugger_stops_at_breakpoints_and_watches() {
+        let mut debugger = Debugger::new(parse_input(PART_ONE_EXAMPLE).unwrap());
+        debugger.add_breakpoint(2);
+        assert_eq!(debugger.resume(), Ok(Stop::Breakpoint(2)));
+        assert_eq!(debugger.output(), [4]);
+        assert_eq!(debugger.trace().len(), 2);
+        debugger.remove_breakpoint(2);
+        debugger.watch(Register::A);
+        assert_eq!(
+            debugger.resume(),
+            Ok(Stop::Watch {
+                register: Register::A,
+                old: 364,
+                new: 182
+            })
+        );
+        assert_eq!(debugger.trace()[2].instruction.opcode, Opcode::Jnz);
+
+        let mut debugger = Debugger::new(parse_input(PART_ONE_EXAMPLE).unwrap());
+        debugger.set_budget(8);
+        assert_eq!(debugger.resume(), Err(VmError::OutOfSteps { budget: 8 }));
+    }
+
+    #[test]
+    fn endless_programs_run_out_of_steps() {
+        let mut vm = parse_input(PART_ONE_EXAMPLE.replace("0,1,", "3,0,").as_str()).unwrap();
+        assert_eq!(vm.run(100), Err(VmError::OutOfSteps { budget: 100 }));
+    }
+
+    #[test]
+    fn wide_shifts_clear_the_register_and_halted_programs_stop() {
+        // cdv by B = 64, then adv by C = 0.
+        let mut vm =
+            parse_input("Register A: 12345\nRegister B: 64\nRegister C: 9\n\nProgram: 7,5,0,6\n")
+                .unwrap();
+        assert_eq!(vm.run(10), Ok(vec![]));
+        assert_eq!(vm.registers().c, 0);
+        assert_eq!(vm.registers().a, 12345);
+        assert_eq!(vm.step(), Err(VmError::Halted { ip: 2 }));
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(
+            part_two(&parse_input(PART_TWO_EXAMPLE).unwrap()),
+            Ok(117440)
+        );
+    }
+
+    #[test]
+    fn quines_are_found_by_emulation() {
+        // A program shaped like a real input, and one that only drops two
+        // bits of A per loop.
+        for (program, expected) in [
+            ("2,4,1,5,7,5,1,6,0,3,4,0,5,5,3,0", 105843716614554),
+            ("0,2,2,4,5,5,3,0", 54432),
+        ] {
+            let vm = parse_input(&PART_TWO_EXAMPLE.replace("0,3,5,4,3,0", program)).unwrap();
+            assert_eq!(find_quine(&vm), Ok(expected));
+            assert_eq!(vm.output_for(expected).unwrap(), vm.raw_instructions);
+        }
+        let vm = parse_input(&PART_TWO_EXAMPLE.replace("0,3,5,4,3,0", "0,2,2,4,5,5,3,0")).unwrap();
+        assert!((0..54432).all(|a| vm.output_for(a).unwrap() != vm.raw_instructions));
+    }
+
+    #[test]
+    fn programs_without_quines_are_rejected() {
+        let no_shift = PART_TWO_EXAMPLE.replace("0,3,5,4,3,0", "5,4,1,1");
+        assert_eq!(
+            find_quine(&parse_input(&no_shift).unwrap()),
+            Err(QuineError::UnsupportedShape)
+        );
+        let prints_b = PART_TWO_EXAMPLE.replace("0,3,5,4,3,0", "0,3,5,5,3,0");
+        assert_eq!(
+            find_quine(&parse_input(&prints_b).unwrap()),
+            Err(QuineError::NoSolution)
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use aoc_common::{Direction, ParseError, non_empty_lines, search::bfs};
+use serde::Deserialize;
+use std::{collections::HashSet, fmt};
+
+type Point = (i32, i32);
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// The memory space is `dims.0` by `dims.1`.
+    pub dims: (i32, i32),
+    /// How many bytes have fallen by part one.
+    pub prefix_size: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            dims: (71, 71),
+            prefix_size: 1024,
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Point>, ParseError> {
+    non_empty_lines(input)
+        .map(|line| {
+            let [x, y] = line.split_exact(",")?;
+            Ok((x.parse()?, y.parse()?))
+        })
+        .collect()
+}
+
+fn distance(banned: &[Point], rows: i32, cols: i32) -> Option<i32> {
+    let banned_set: HashSet<Point> = banned.iter().copied().collect();
+    let search = bfs([(0, 0)], |&(x, y)| {
+        Direction::ALL
+            .map(|direction| {
+                let delta = direction.delta();
+                (x + delta.row, y + delta.col)
+            })
+            .into_iter()
+            .filter(|&(nx, ny)| {
+                nx >= 0 && nx < rows && ny >= 0 && ny < cols && !banned_set.contains(&(nx, ny))
+            })
+    });
+    search.distance(&(rows - 1, cols - 1)).map(|d| d as i32)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MemoryError {
+    /// `prefix_size` asks for more bytes than the input lists.
+    TooFewBytes { prefix_size: usize, bytes: usize },
+    /// The exit can't be reached after the first `prefix_size` bytes.
+    NoPath { prefix_size: usize },
+    /// The exit can still be reached once every byte has fallen.
+    NeverBlocked,
+}
+
+impl fmt::Display for MemoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MemoryError::TooFewBytes { prefix_size, bytes } => {
+                write!(
+                    f,
+                    "prefix_size is {prefix_size}, but only {bytes} bytes fall"
+                )
+            }
+            MemoryError::NoPath { prefix_size } => {
+                write!(f, "the exit can't be reached after {prefix_size} bytes")
+            }
+            MemoryError::NeverBlocked => write!(f, "no byte cuts off the exit"),
+        }
+    }
+}
+
+impl std::error::Error for MemoryError {}
+
+fn first_blocking_point(whole_banned: &[Point], rows: i32, cols: i32) -> Option<Point> {
+    // The exit is reachable after `lo` bytes, and cut off after `hi + 1`.
+    let (mut lo, mut hi) = (0, whole_banned.len());
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        if distance(&whole_banned[..=mid], rows, cols).is_some() {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    whole_banned.get(lo).copied()
+}
+
+pub fn part_one(coordinates: &[Point], params: &Params) -> Result<i32, MemoryError> {
+    let (rows, cols) = params.dims;
+    let prefix_size = params.prefix_size;
+    let fallen = coordinates
+        .get(..prefix_size)
+        .ok_or(MemoryError::TooFewBytes {
+            prefix_size,
+            bytes: coordinates.len(),
+        })?;
+    distance(fallen, rows, cols).ok_or(MemoryError::NoPath { prefix_size })
+}
+
+pub fn part_two(coordinates: &[Point], params: &Params) -> Result<String, MemoryError> {
+    let (rows, cols) = params.dims;
+    let (x, y) = first_blocking_point(coordinates, rows, cols).ok_or(MemoryError::NeverBlocked)?;
+    Ok(format!("{x},{y}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+
+    const PARAMS: Params = Params {
+        dims: (7, 7),
+        prefix_size: 12,
+    };
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap(), &PARAMS), Ok(22));
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(
+            part_two(&parse_input(EXAMPLE).unwrap(), &PARAMS),
+            Ok("6,1".to_string())
+        );
+    }
+
+    #[test]
+    fn bad_params_are_errors() {
+        let coordinates = parse_input("1,0\n0,1\n").unwrap();
+        let params = Params {
+            dims: (3, 3),
+            prefix_size: 3,
+        };
+        let too_few = MemoryError::TooFewBytes {
+            prefix_size: 3,
+            bytes: 2,
+        };
+        assert_eq!(part_one(&coordinates, &params), Err(too_few));
+        let params = Params {
+            prefix_size: 2,
+            ..params
+        };
+        assert_eq!(
+            part_one(&coordinates, &params),
+            Err(MemoryError::NoPath { prefix_size: 2 })
+        );
+        assert_eq!(part_two(&coordinates, &params), Ok("0,1".to_string()));
+        assert_eq!(
+            part_two(&coordinates[..1], &params),
+            Err(MemoryError::NeverBlocked)
+        );
+    }
+}
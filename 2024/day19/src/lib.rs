@@ -0,0 +1,81 @@
+use aoc_common::{ParseError, split_sections};
+
+type Patterns = Vec<String>;
+type Targets = Vec<String>;
+
+pub type Input = (Patterns, Targets);
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let [patterns, targets] = split_sections(input)?;
+    if let Some(extra) = patterns.get(1) {
+        return Err(extra.error("a blank line"));
+    }
+    let patterns = patterns[0]
+        .split(", ")
+        .map(|v| match v.is_empty() {
+            true => Err(v.error("a towel pattern")),
+            false => Ok(v.text().to_string()),
+        })
+        .collect::<Result<_, _>>()?;
+    let targets = targets.iter().map(|line| line.text().to_string()).collect();
+
+    Ok((patterns, targets))
+}
+
+fn possibilities(patterns: &Vec<String>, target: &str) -> u64 {
+    let mut ways = vec![0; target.len() + 1];
+    ways[0] = 1;
+    for prefix in 0..=target.len() {
+        if ways[prefix] == 0 {
+            continue;
+        }
+        for pat in patterns {
+            if prefix + pat.len() <= target.len() && target[prefix..prefix + pat.len()] == *pat {
+                ways[prefix + pat.len()] += ways[prefix];
+            }
+        }
+    }
+    ways[target.len()]
+}
+
+pub fn part_one((patterns, targets): &Input) -> usize {
+    targets
+        .iter()
+        .filter(|target| possibilities(patterns, target) > 0)
+        .count()
+}
+
+pub fn part_two((patterns, targets): &Input) -> u64 {
+    targets
+        .iter()
+        .map(|target| possibilities(patterns, target))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 6);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 16);
+    }
+}
@@ -0,0 +1,183 @@
+use aoc_common::{
+    Grid, ParseError, Point,
+    search::{self, Search},
+};
+use itertools::Itertools;
+use serde::Deserialize;
+use std::{collections::BTreeMap, fmt};
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Only cheats saving at least this many picoseconds are counted.
+    pub min_saving: i32,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { min_saving: 100 }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RaceError {
+    /// Every cheat saves at least one picosecond, so smaller thresholds
+    /// would count moves that aren't cheats at all.
+    BadMinSaving(i32),
+    /// The walls cut the end off from the start.
+    Unreachable,
+}
+
+impl fmt::Display for RaceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RaceError::BadMinSaving(min_saving) => {
+                write!(f, "min_saving must be positive, found {min_saving}")
+            }
+            RaceError::Unreachable => write!(f, "the end can't be reached from the start"),
+        }
+    }
+}
+
+impl std::error::Error for RaceError {}
+
+impl Params {
+    fn min_saving(&self) -> Result<i32, RaceError> {
+        match self.min_saving {
+            min_saving if min_saving > 0 => Ok(min_saving),
+            min_saving => Err(RaceError::BadMinSaving(min_saving)),
+        }
+    }
+}
+
+pub struct Racetrack {
+    grid: Grid<char>,
+    start: Point,
+    end: Point,
+}
+
+pub fn parse_input(input: &str) -> Result<Racetrack, ParseError> {
+    let grid = Grid::parse_from_str(input)?;
+    let start = grid
+        .find(&'S')
+        .ok_or_else(|| ParseError::end_of_input(input, "a start tile (`S`)"))?;
+    let end = grid
+        .find(&'E')
+        .ok_or_else(|| ParseError::end_of_input(input, "an end tile (`E`)"))?;
+    Ok(Racetrack { grid, start, end })
+}
+
+fn bfs(racetrack: &Racetrack, source: Point) -> Search<Point> {
+    search::bfs([source], |&cell| {
+        racetrack
+            .grid
+            .neighbours(cell)
+            .filter(|&next_cell| racetrack.grid[next_cell] != '#')
+    })
+}
+
+fn find_shortcuts(
+    racetrack: &Racetrack,
+    middle_path_len: i32,
+    min_saving: i32,
+) -> Result<i32, RaceError> {
+    let start_distances = bfs(racetrack, racetrack.start);
+    let end_distances = bfs(racetrack, racetrack.end);
+    let old_distance = start_distances
+        .distance(&racetrack.end)
+        .ok_or(RaceError::Unreachable)? as i32;
+    let mut saved_distances_count: BTreeMap<i32, i32> = BTreeMap::new();
+    for cheat_start in racetrack.grid.points() {
+        let Some(start_distance) = start_distances.distance(&cheat_start) else {
+            continue;
+        };
+        for offset in (-middle_path_len..=middle_path_len)
+            .cartesian_product(-middle_path_len..=middle_path_len)
+            .map(|(d_row, d_col)| Point::new(d_row, d_col))
+        {
+            let cheat_end = cheat_start + offset;
+            if let Some(end_distance) = end_distances.distance(&cheat_end) {
+                let manhattan = cheat_start.manhattan_distance(cheat_end);
+                if manhattan <= middle_path_len {
+                    let new_distance = manhattan + (start_distance + end_distance) as i32;
+                    *saved_distances_count
+                        .entry(old_distance - new_distance)
+                        .or_insert(0) += 1;
+                }
+            }
+        }
+    }
+    let mut res = 0;
+    for (saved_distance, count) in saved_distances_count.into_iter() {
+        if saved_distance >= min_saving {
+            res += count;
+        }
+    }
+    Ok(res)
+}
+
+pub fn part_one(racetrack: &Racetrack, params: &Params) -> Result<i32, RaceError> {
+    find_shortcuts(racetrack, 2, params.min_saving()?)
+}
+
+pub fn part_two(racetrack: &Racetrack, params: &Params) -> Result<i32, RaceError> {
+    find_shortcuts(racetrack, 20, params.min_saving()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+
+    #[test]
+    fn race_length_in_example() {
+        let racetrack = parse_input(EXAMPLE).unwrap();
+        assert_eq!(
+            bfs(&racetrack, racetrack.start).distance(&racetrack.end),
+            Some(84)
+        );
+    }
+
+    #[test]
+    fn part_one_example() {
+        let params = Params { min_saving: 64 };
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap(), &params), Ok(1));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let params = Params { min_saving: 76 };
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap(), &params), Ok(3));
+    }
+
+    #[test]
+    fn bad_params_are_errors() {
+        let racetrack = parse_input(EXAMPLE).unwrap();
+        let params = Params { min_saving: 0 };
+        assert_eq!(
+            part_one(&racetrack, &params),
+            Err(RaceError::BadMinSaving(0))
+        );
+        assert_eq!(
+            part_two(&racetrack, &params),
+            Err(RaceError::BadMinSaving(0))
+        );
+    }
+}
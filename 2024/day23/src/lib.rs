@@ -0,0 +1,410 @@
+use aoc_common::{ParseError, non_empty_lines};
+use std::collections::BTreeSet;
+
+/// Enough 64-bit words for a bit per two-letter name.
+const WORDS: usize = (26 * 26usize).div_ceil(64);
+
+/// A set of vertex IDs stored as one bit per possible ID, so that set
+/// operations work a word at a time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct VertexSet([u64; WORDS]);
+
+impl VertexSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, id: u16) {
+        self.0[id as usize / 64] |= 1 << (id % 64);
+    }
+
+    pub fn remove(&mut self, id: u16) {
+        self.0[id as usize / 64] &= !(1 << (id % 64));
+    }
+
+    pub fn contains(&self, id: u16) -> bool {
+        self.0[id as usize / 64] >> (id % 64) & 1 == 1
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|&word| word == 0)
+    }
+
+    fn zip_with(&self, other: &Self, f: impl Fn(u64, u64) -> u64) -> Self {
+        VertexSet(std::array::from_fn(|idx| f(self.0[idx], other.0[idx])))
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a & b)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a | b)
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a & !b)
+    }
+
+    /// The IDs in the set, smallest first.
+    pub fn iter(&self) -> impl Iterator<Item = u16> + '_ {
+        self.0.iter().enumerate().flat_map(|(idx, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                (word != 0).then(|| {
+                    let bit = word.trailing_zeros();
+                    word &= word - 1;
+                    (idx * 64) as u16 + bit as u16
+                })
+            })
+        })
+    }
+}
+
+impl FromIterator<u16> for VertexSet {
+    fn from_iter<I: IntoIterator<Item = u16>>(ids: I) -> Self {
+        let mut set = VertexSet::new();
+        for id in ids {
+            set.insert(id);
+        }
+        set
+    }
+}
+
+#[derive(Default)]
+struct NetworkBuilder {
+    edges: Vec<(String, String)>,
+}
+
+/// Computers numbered by their names in sorted order, so that ID order is
+/// name order.
+#[derive(Debug)]
+pub struct Network {
+    names: Vec<String>,
+    adjacency: Vec<VertexSet>,
+}
+
+impl NetworkBuilder {
+    fn add_edge(&mut self, a: String, b: String) {
+        self.edges.push((a, b));
+    }
+
+    fn build(&self) -> Network {
+        let names: BTreeSet<&String> = self.edges.iter().flat_map(|(a, b)| [a, b]).collect();
+        let names: Vec<String> = names.into_iter().cloned().collect();
+        let id = |name: &String| names.binary_search(name).unwrap();
+        let mut adjacency = vec![VertexSet::new(); names.len()];
+        for (a, b) in self.edges.iter() {
+            let (a, b) = (id(a), id(b));
+            adjacency[a].insert(b as u16);
+            adjacency[b].insert(a as u16);
+        }
+        Network { names, adjacency }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Network, ParseError> {
+    let mut builder = NetworkBuilder::default();
+    for line in non_empty_lines(input) {
+        let [a, b] = line.split_exact("-")?;
+        for name in [a, b] {
+            if name.text().len() != 2 || !name.text().bytes().all(|c| c.is_ascii_lowercase()) {
+                return Err(name.error("a two-letter computer name"));
+            }
+        }
+        builder.add_edge(a.text().to_string(), b.text().to_string());
+    }
+    Ok(builder.build())
+}
+
+impl Network {
+    /// How many computers there are. IDs run from zero up to this.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn id(&self, name: &str) -> Option<u16> {
+        self.names
+            .binary_search_by(|other| other.as_str().cmp(name))
+            .ok()
+            .map(|id| id as u16)
+    }
+
+    pub fn name(&self, id: u16) -> &str {
+        &self.names[id as usize]
+    }
+
+    pub fn neighbours(&self, id: u16) -> &VertexSet {
+        &self.adjacency[id as usize]
+    }
+
+    pub fn degree(&self, id: u16) -> usize {
+        self.neighbours(id).len()
+    }
+
+    pub fn is_adjacent(&self, a: u16, b: u16) -> bool {
+        self.neighbours(a).contains(b)
+    }
+
+    fn ids(&self) -> impl Iterator<Item = u16> + use<> {
+        0..self.len() as u16
+    }
+
+    /// Repeatedly takes a vertex of the smallest remaining degree, so that
+    /// every vertex has few neighbours later in the order.
+    fn degeneracy_order(&self) -> Vec<u16> {
+        let mut degrees: Vec<usize> = self.ids().map(|id| self.degree(id)).collect();
+        let mut queue: BTreeSet<(usize, u16)> =
+            self.ids().map(|id| (degrees[id as usize], id)).collect();
+        let mut remaining: VertexSet = self.ids().collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some((_, vertex)) = queue.pop_first() {
+            order.push(vertex);
+            remaining.remove(vertex);
+            for neighbour in self.neighbours(vertex).intersection(&remaining).iter() {
+                let degree = &mut degrees[neighbour as usize];
+                queue.remove(&(*degree, neighbour));
+                *degree -= 1;
+                queue.insert((*degree, neighbour));
+            }
+        }
+        order
+    }
+
+    /// Bron–Kerbosch with pivoting: reports every maximal clique that
+    /// extends `clique` with some of `candidates` and none of `excluded`.
+    fn bron_kerbosch(
+        &self,
+        clique: &mut Vec<u16>,
+        mut candidates: VertexSet,
+        mut excluded: VertexSet,
+        cliques: &mut Vec<Vec<u16>>,
+    ) {
+        let Some(pivot) = candidates
+            .union(&excluded)
+            .iter()
+            .max_by_key(|&pivot| candidates.intersection(self.neighbours(pivot)).len())
+        else {
+            cliques.push(clique.clone());
+            return;
+        };
+        // Every maximal clique holds the pivot or one of its non-neighbours.
+        for vertex in candidates.difference(self.neighbours(pivot)).iter() {
+            let neighbours = self.neighbours(vertex);
+            clique.push(vertex);
+            self.bron_kerbosch(
+                clique,
+                candidates.intersection(neighbours),
+                excluded.intersection(neighbours),
+                cliques,
+            );
+            clique.pop();
+            candidates.remove(vertex);
+            excluded.insert(vertex);
+        }
+    }
+
+    /// Every clique that no other vertex can join, each sorted by name.
+    pub fn maximal_cliques(&self) -> Vec<Vec<&str>> {
+        let mut earlier = VertexSet::new();
+        let mut cliques = Vec::new();
+        for vertex in self.degeneracy_order() {
+            let neighbours = self.neighbours(vertex);
+            let later = neighbours.difference(&earlier);
+            self.bron_kerbosch(
+                &mut vec![vertex],
+                later,
+                neighbours.intersection(&earlier),
+                &mut cliques,
+            );
+            earlier.insert(vertex);
+        }
+        let mut cliques: Vec<Vec<&str>> = cliques
+            .into_iter()
+            .map(|mut clique| {
+                clique.sort();
+                clique.into_iter().map(|id| self.name(id)).collect()
+            })
+            .collect();
+        cliques.sort();
+        cliques
+    }
+
+    /// The largest clique, sorted by name. Among cliques of the same size,
+    /// the one that sorts first wins.
+    pub fn max_clique(&self) -> Vec<&str> {
+        self.maximal_cliques()
+            .into_iter()
+            .max_by(|a, b| a.len().cmp(&b.len()).then_with(|| b.cmp(a)))
+            .unwrap_or_default()
+    }
+
+    /// How many cliques of `size` computers have one whose name starts with
+    /// `prefix`.
+    pub fn count_cliques(&self, size: usize, prefix: &str) -> usize {
+        let marked = self
+            .ids()
+            .filter(|&id| self.name(id).starts_with(prefix))
+            .collect();
+        self.count_extensions(size, &marked, false, self.ids().collect())
+    }
+
+    /// Counts the ways to pick `size` more vertices from `candidates`, which
+    /// are all adjacent to the clique so far, each set counted once.
+    fn count_extensions(
+        &self,
+        size: usize,
+        marked: &VertexSet,
+        has_marked: bool,
+        mut candidates: VertexSet,
+    ) -> usize {
+        if size == 0 {
+            return has_marked as usize;
+        }
+        let mut count = 0;
+        while candidates.len() >= size {
+            let Some(vertex) = candidates.iter().next() else {
+                break;
+            };
+            candidates.remove(vertex);
+            count += self.count_extensions(
+                size - 1,
+                marked,
+                has_marked || marked.contains(vertex),
+                candidates.intersection(self.neighbours(vertex)),
+            );
+        }
+        count
+    }
+}
+
+pub fn part_one(network: &Network) -> usize {
+    network.count_cliques(3, "t")
+}
+
+pub fn part_two(network: &Network) -> String {
+    network.max_clique().join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 7);
+    }
+
+    #[test]
+    fn names_are_interned_in_sorted_order() {
+        let network = parse_input(EXAMPLE).unwrap();
+        assert_eq!(network.len(), 16);
+        assert_eq!(network.id("aq"), Some(0));
+        assert_eq!(network.id("zz"), None);
+        let ta = network.id("ta").unwrap();
+        assert_eq!(network.name(ta), "ta");
+        assert_eq!(network.degree(ta), 4);
+        let neighbours: Vec<&str> = network
+            .neighbours(ta)
+            .iter()
+            .map(|id| network.name(id))
+            .collect();
+        assert_eq!(neighbours, ["co", "de", "ka", "kh"]);
+        assert!(parse_input("kh-tc\nqp-k\n").is_err());
+    }
+
+    #[test]
+    fn blank_lines_are_skipped() {
+        let network = parse_input("kh-tc\n\nqp-kh\n\n").unwrap();
+        assert_eq!(network.len(), 3);
+        assert!(parse_input("kh-tc\n\nqp\n").is_err());
+    }
+
+    #[test]
+    fn vertex_sets_work_a_word_at_a_time() {
+        let a: VertexSet = [0, 63, 64, 675].into_iter().collect();
+        let b: VertexSet = [63, 100, 675].into_iter().collect();
+        assert_eq!(a.intersection(&b).iter().collect::<Vec<_>>(), [63, 675]);
+        assert_eq!(a.union(&b).len(), 5);
+        assert_eq!(a.difference(&b).iter().collect::<Vec<_>>(), [0, 64]);
+        assert!(a.contains(675) && !a.contains(100));
+    }
+
+    #[test]
+    fn cliques_of_every_size_are_counted() {
+        let network = parse_input(EXAMPLE).unwrap();
+        assert_eq!(network.count_cliques(2, ""), 32);
+        assert_eq!(network.count_cliques(3, ""), 12);
+        assert_eq!(network.count_cliques(4, ""), 1);
+        assert_eq!(network.count_cliques(5, ""), 0);
+        assert_eq!(network.count_cliques(3, "co"), 3);
+    }
+
+    #[test]
+    fn maximal_cliques_cannot_grow() {
+        let network = parse_input(EXAMPLE).unwrap();
+        let cliques = network.maximal_cliques();
+        for clique in &cliques {
+            let members: VertexSet = clique
+                .iter()
+                .map(|&name| network.id(name).unwrap())
+                .collect();
+            let joinable = members
+                .iter()
+                .fold(network.ids().collect(), |joinable: VertexSet, member| {
+                    joinable.intersection(network.neighbours(member))
+                });
+            assert!(joinable.is_empty(), "{clique:?}");
+        }
+        assert!(cliques.contains(&vec!["co", "de", "ka", "ta"]));
+        assert!(cliques.contains(&vec!["aq", "cg", "yn"]));
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), "co,de,ka,ta");
+    }
+}
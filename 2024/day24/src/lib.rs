@@ -0,0 +1,833 @@
+use aoc_common::{
+    ParseError, split_sections,
+    toposort::{Cycle, Dag, TieBreak},
+};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Write},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Xor,
+    Or,
+    And,
+}
+
+#[derive(Debug, Clone)]
+enum NodeType {
+    Simple(i64),
+    Operated(Operator, (String, String)),
+}
+
+#[derive(Debug, Clone)]
+pub struct Network {
+    nodes: HashMap<String, NodeType>,
+}
+
+pub fn parse_input(input: &str) -> Result<Network, ParseError> {
+    let [initial_values, gates] = split_sections(input)?;
+    let mut nodes = HashMap::new();
+    for line in initial_values {
+        let (node_name, node_value) = line.split_once(": ")?;
+        let node_value = match node_value.text() {
+            "0" => 0,
+            "1" => 1,
+            _ => return Err(node_value.error("`0` or `1`")),
+        };
+        nodes.insert(node_name.text().to_owned(), NodeType::Simple(node_value));
+    }
+    let mut operand_spans = Vec::new();
+    for line in gates {
+        let (operation, result) = line.split_once(" -> ")?;
+        let [operand_1, operator, operand_2] = operation.split_exact(" ")?;
+        let operator = match operator.text() {
+            "XOR" => Operator::Xor,
+            "AND" => Operator::And,
+            "OR" => Operator::Or,
+            _ => return Err(operator.error("one of `XOR`, `AND` or `OR`")),
+        };
+        let operands = (operand_1.text().to_string(), operand_2.text().to_string());
+        operand_spans.extend([operand_1, operand_2]);
+        nodes.insert(
+            result.text().to_string(),
+            NodeType::Operated(operator, operands),
+        );
+    }
+    if let Some(undefined) = operand_spans
+        .iter()
+        .find(|operand| !nodes.contains_key(operand.text()))
+    {
+        return Err(undefined.error("a wire with a value or a gate"));
+    }
+    Ok(Network { nodes })
+}
+
+/// A network compiled into gates over numbered wires, in an order where
+/// every gate comes after the gates it reads from. Each wire holds a `u64`
+/// word, so that 64 assignments of the inputs are evaluated at once.
+#[derive(Debug, Clone)]
+pub struct Circuit {
+    names: Vec<String>,
+    /// The word on every wire before any gate runs: the initial values
+    /// repeated across all 64 lanes.
+    initial: Vec<u64>,
+    gates: Vec<(Operator, usize, usize, usize)>,
+    x: Vec<usize>,
+    y: Vec<usize>,
+    z: Vec<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitError {
+    /// Some wires depend on themselves.
+    Cycle(Cycle<String>),
+    /// A bus has more wires than the bits of the numbers it carries.
+    TooWide { bus: char, wires: usize, max: usize },
+}
+
+impl fmt::Display for CircuitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CircuitError::Cycle(cycle) => write!(f, "the circuit has a {cycle}"),
+            CircuitError::TooWide { bus, wires, max } => {
+                write!(
+                    f,
+                    "`{bus}` has {wires} wires, but at most {max} are supported"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for CircuitError {}
+
+impl Network {
+    /// Orders the gates once, so the circuit can be evaluated many times.
+    /// Fails if some wire depends on itself, or if the sum of two inputs
+    /// might not fit in a `u64`.
+    pub fn compile(&self) -> Result<Circuit, CircuitError> {
+        let mut names: Vec<&str> = self.nodes.keys().map(String::as_str).collect();
+        names.sort();
+        let index: HashMap<&str, usize> = names
+            .iter()
+            .enumerate()
+            .map(|(idx, &name)| (name, idx))
+            .collect();
+        let mut dag = Dag::new();
+        for idx in 0..names.len() {
+            dag.add_node(idx);
+        }
+        let gates = self.gates();
+        for &(output, _, a, b) in &gates {
+            dag.add_edge(index[a], index[output])
+                .add_edge(index[b], index[output]);
+        }
+        let order = dag.kahn(TieBreak::Insertion).map_err(|Cycle(cycle)| {
+            CircuitError::Cycle(Cycle(
+                cycle
+                    .into_iter()
+                    .map(|idx| names[idx].to_string())
+                    .collect(),
+            ))
+        })?;
+        let mut rank = vec![0; names.len()];
+        for (position, idx) in order.into_iter().enumerate() {
+            rank[idx] = position;
+        }
+        let mut gates: Vec<_> = gates
+            .into_iter()
+            .map(|(output, op, a, b)| (op, index[a], index[b], index[output]))
+            .collect();
+        gates.sort_by_key(|&(.., output)| rank[output]);
+        let initial = names
+            .iter()
+            .map(|&name| match self.nodes[name] {
+                NodeType::Simple(1) => u64::MAX,
+                _ => 0,
+            })
+            .collect();
+        let bus = |prefix: char, max: usize| -> Result<Vec<usize>, CircuitError> {
+            let wires: Vec<usize> = (0..names.len())
+                .filter(|&idx| names[idx].starts_with(prefix))
+                .collect();
+            if wires.len() > max {
+                return Err(CircuitError::TooWide {
+                    bus: prefix,
+                    wires: wires.len(),
+                    max,
+                });
+            }
+            Ok(wires)
+        };
+        Ok(Circuit {
+            initial,
+            gates,
+            x: bus('x', 63)?,
+            y: bus('y', 63)?,
+            z: bus('z', 64)?,
+            names: names.into_iter().map(str::to_string).collect(),
+        })
+    }
+}
+
+impl Circuit {
+    /// Runs the circuit on 64 assignments at once. Bit `lane` of `x[bit]` is
+    /// the value of input `x` bit `bit` in assignment `lane`, and likewise
+    /// for `y` and the returned `z` words. Inputs left out keep their
+    /// initial values.
+    pub fn evaluate_sliced(&self, x: &[u64], y: &[u64]) -> Vec<u64> {
+        let wires = self.run(x, y);
+        self.z.iter().map(|&idx| wires[idx]).collect()
+    }
+
+    /// The word on every wire.
+    fn run(&self, x: &[u64], y: &[u64]) -> Vec<u64> {
+        let mut wires = self.initial.clone();
+        for (&idx, &word) in self.x.iter().zip(x).chain(self.y.iter().zip(y)) {
+            wires[idx] = word;
+        }
+        for &(op, a, b, output) in &self.gates {
+            wires[output] = op.apply(wires[a], wires[b]);
+        }
+        wires
+    }
+
+    /// The value on every wire with the initial values of the inputs, as
+    /// the renders of the network show them.
+    pub fn wire_values(&self) -> HashMap<String, u64> {
+        let wires = self.run(&[], &[]);
+        self.names
+            .iter()
+            .zip(wires)
+            .map(|(name, word)| (name.clone(), word & 1))
+            .collect()
+    }
+
+    /// The number on `z` for each pair of numbers on `x` and `y`, 64 pairs
+    /// at a time.
+    pub fn set_inputs(&self, inputs: &[(u64, u64)]) -> Vec<u64> {
+        let mut outputs = Vec::with_capacity(inputs.len());
+        for chunk in inputs.chunks(64) {
+            let slice = |bus: &[usize], number: fn(&(u64, u64)) -> u64| -> Vec<u64> {
+                (0..bus.len())
+                    .map(|bit| {
+                        chunk.iter().enumerate().fold(0, |word, (lane, pair)| {
+                            word | (number(pair) >> bit & 1) << lane
+                        })
+                    })
+                    .collect()
+            };
+            let z =
+                self.evaluate_sliced(&slice(&self.x, |&(x, _)| x), &slice(&self.y, |&(_, y)| y));
+            outputs.extend((0..chunk.len()).map(|lane| {
+                z.iter()
+                    .enumerate()
+                    .fold(0, |number, (bit, word)| number | (word >> lane & 1) << bit)
+            }));
+        }
+        outputs
+    }
+
+    /// The number on `z` when `x` and `y` hold these numbers.
+    pub fn set_input(&self, x: u64, y: u64) -> u64 {
+        self.set_inputs(&[(x, y)])[0]
+    }
+
+    /// The number on `z` with the initial values of the inputs.
+    pub fn output(&self) -> u64 {
+        let z = self.evaluate_sliced(&[], &[]);
+        z.iter()
+            .enumerate()
+            .fold(0, |number, (bit, word)| number | (word & 1) << bit)
+    }
+}
+
+pub fn part_one(network: &Network) -> Result<u64, CircuitError> {
+    Ok(network.compile()?.output())
+}
+
+fn wire(prefix: char, bit: usize) -> String {
+    format!("{prefix}{bit:02}")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdderError {
+    /// A gate that every bit of a ripple-carry adder needs is missing.
+    MissingGate(String),
+    /// A bit can only be repaired by moving a gate onto a wire that no gate
+    /// drives.
+    MissingWire(String),
+    /// Fixing one bit keeps breaking another.
+    TooManySwaps,
+    /// The circuit still adds these two numbers wrong after the swaps.
+    WrongSum { x: u64, y: u64 },
+    /// The swaps made a circuit that can't be evaluated.
+    Circuit(CircuitError),
+}
+
+impl fmt::Display for AdderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AdderError::MissingGate(gate) => write!(f, "the adder has no `{gate}` gate"),
+            AdderError::MissingWire(wire) => write!(f, "no gate drives `{wire}`"),
+            AdderError::TooManySwaps => write!(f, "no small set of swaps repairs the adder"),
+            AdderError::WrongSum { x, y } => write!(f, "the repaired adder gets {x} + {y} wrong"),
+            AdderError::Circuit(err) => write!(f, "the repaired adder is broken: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for AdderError {}
+
+type Swap = (String, String);
+
+impl Network {
+    fn input_bits(&self) -> usize {
+        self.nodes
+            .keys()
+            .filter(|name| name.starts_with('x'))
+            .count()
+    }
+
+    /// The output of the gate applying `op` to `a` and `b`, in either order.
+    fn gate(&self, op: Operator, a: &str, b: &str) -> Option<&str> {
+        self.nodes.iter().find_map(|(output, node)| match node {
+            NodeType::Operated(gate_op, (p, q))
+                if *gate_op == op && ((p == a && q == b) || (p == b && q == a)) =>
+            {
+                Some(output.as_str())
+            }
+            _ => None,
+        })
+    }
+
+    fn expect_gate(&self, op: Operator, a: &str, b: &str) -> Result<&str, AdderError> {
+        self.gate(op, a, b)
+            .ok_or_else(|| AdderError::MissingGate(format!("{a} {} {b}", op.name())))
+    }
+
+    /// The other input of a gate applying `op` to `input`.
+    fn other_input(&self, op: Operator, input: &str) -> Option<&str> {
+        self.nodes.values().find_map(|node| match node {
+            NodeType::Operated(gate_op, (p, q)) if *gate_op == op && p == input => Some(q.as_str()),
+            NodeType::Operated(gate_op, (p, q)) if *gate_op == op && q == input => Some(p.as_str()),
+            _ => None,
+        })
+    }
+
+    fn is_gate_output(&self, wire: &str) -> bool {
+        matches!(self.nodes.get(wire), Some(NodeType::Operated(..)))
+    }
+
+    /// Swaps the gates driving `a` and `b`, which [`Network::next_swap`]
+    /// has checked are both gate outputs.
+    fn swap_outputs(&mut self, (a, b): &Swap) {
+        let gate_a = self
+            .nodes
+            .remove(a)
+            .expect("swapped wires are gate outputs");
+        let gate_b = self
+            .nodes
+            .insert(b.clone(), gate_a)
+            .expect("swapped wires are gate outputs");
+        self.nodes.insert(a.clone(), gate_b);
+    }
+
+    /// Walks the adder from the least significant bit, checking that every
+    /// bit has the gates of a full adder:
+    ///
+    /// ```text
+    /// sum     = x XOR y          z = sum XOR carry_in
+    /// and     = x AND y          carry_out = and OR through
+    /// through = sum AND carry_in
+    /// ```
+    ///
+    /// Returns the first swap of outputs that makes a bit look right.
+    fn next_swap(&self) -> Result<Option<Swap>, AdderError> {
+        let swap = |a: &str, b: &str| match [a, b].into_iter().find(|w| !self.is_gate_output(w)) {
+            Some(missing) => Err(AdderError::MissingWire(missing.to_string())),
+            None => Ok(Some((a.to_string(), b.to_string()))),
+        };
+        let bits = self.input_bits();
+        let z00 = self.expect_gate(Operator::Xor, "x00", "y00")?;
+        if z00 != "z00" {
+            return swap(z00, "z00");
+        }
+        let mut carry = self.expect_gate(Operator::And, "x00", "y00")?;
+        for bit in 1..bits {
+            let (x, y, z) = (wire('x', bit), wire('y', bit), wire('z', bit));
+            let sum = self.expect_gate(Operator::Xor, &x, &y)?;
+            let and = self.expect_gate(Operator::And, &x, &y)?;
+            let Some(output) = self.gate(Operator::Xor, sum, carry) else {
+                if let Some(other) = self.other_input(Operator::Xor, carry) {
+                    return swap(sum, other);
+                }
+                if let Some(other) = self.other_input(Operator::Xor, sum) {
+                    return swap(carry, other);
+                }
+                return Err(AdderError::MissingGate(format!("{sum} XOR {carry}")));
+            };
+            if output != z {
+                return swap(output, &z);
+            }
+            let through = self.expect_gate(Operator::And, sum, carry)?;
+            let Some(carry_out) = self.gate(Operator::Or, and, through) else {
+                if let Some(other) = self.other_input(Operator::Or, through) {
+                    return swap(and, other);
+                }
+                if let Some(other) = self.other_input(Operator::Or, and) {
+                    return swap(through, other);
+                }
+                return Err(AdderError::MissingGate(format!("{and} OR {through}")));
+            };
+            carry = carry_out;
+        }
+        let last = wire('z', bits);
+        if carry != last {
+            return swap(carry, &last);
+        }
+        Ok(None)
+    }
+
+    /// Adds pseudo-random numbers, plus a few that carry through every bit.
+    fn check_sums(&self) -> Result<(), AdderError> {
+        let circuit = self.compile().map_err(AdderError::Circuit)?;
+        let mask = (1u64 << circuit.x.len()) - 1;
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut random = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state & mask
+        };
+        let pairs: Vec<(u64, u64)> = [(mask, mask), (mask, 1), (0, 0)]
+            .into_iter()
+            .chain((0..1000).map(|_| (random(), random())))
+            .collect();
+        let sums = circuit.set_inputs(&pairs);
+        match pairs
+            .into_iter()
+            .zip(sums)
+            .find(|&((x, y), sum)| sum != x + y)
+        {
+            Some(((x, y), _)) => Err(AdderError::WrongSum { x, y }),
+            None => Ok(()),
+        }
+    }
+
+    /// The pairs of gates whose outputs were swapped, found by repairing
+    /// the adder a bit at a time and confirmed by adding numbers with it.
+    pub fn find_swaps(&self) -> Result<Vec<Swap>, AdderError> {
+        let mut network = self.clone();
+        let mut swaps = Vec::new();
+        while let Some(swap) = network.next_swap()? {
+            if swaps.len() == network.input_bits() {
+                return Err(AdderError::TooManySwaps);
+            }
+            network.swap_outputs(&swap);
+            swaps.push(swap);
+        }
+        network.check_sums()?;
+        Ok(swaps)
+    }
+}
+
+pub fn part_two(network: &Network) -> Result<String, AdderError> {
+    let swaps = network.find_swaps()?;
+    let mut wires: Vec<String> = swaps.into_iter().flat_map(|(a, b)| [a, b]).collect();
+    wires.sort();
+    Ok(wires.join(","))
+}
+
+impl Operator {
+    fn apply(self, a: u64, b: u64) -> u64 {
+        match self {
+            Operator::And => a & b,
+            Operator::Or => a | b,
+            Operator::Xor => a ^ b,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Operator::And => "AND",
+            Operator::Or => "OR",
+            Operator::Xor => "XOR",
+        }
+    }
+
+    fn dot_shape(self) -> &'static str {
+        match self {
+            Operator::And => "box",
+            Operator::Or => "ellipse",
+            Operator::Xor => "diamond",
+        }
+    }
+
+    /// The opening and closing brackets of the node shape.
+    fn mermaid_shape(self) -> (&'static str, &'static str) {
+        match self {
+            Operator::And => ("[", "]"),
+            Operator::Or => ("(", ")"),
+            Operator::Xor => ("{{", "}}"),
+        }
+    }
+}
+
+impl Network {
+    /// The outputs of the gates that [`Network::find_swaps`] swaps back, or
+    /// nothing if the network isn't a broken adder.
+    pub fn suspicious_wires(&self) -> HashSet<String> {
+        self.find_swaps()
+            .unwrap_or_default()
+            .into_iter()
+            .flat_map(|(a, b)| [a, b])
+            .collect()
+    }
+
+    fn gates(&self) -> Vec<(&str, Operator, &str, &str)> {
+        let mut gates: Vec<_> = self
+            .nodes
+            .iter()
+            .filter_map(|(output, node)| match node {
+                NodeType::Operated(op, (a, b)) => {
+                    Some((output.as_str(), *op, a.as_str(), b.as_str()))
+                }
+                NodeType::Simple(_) => None,
+            })
+            .collect();
+        gates.sort_by_key(|&(output, ..)| output);
+        gates
+    }
+
+    /// The wires starting with one of `prefixes`, ordered by bit and then by
+    /// prefix, so that `x00, y00, x01, y01, ...` line up.
+    fn in_bit_order(&self, prefixes: &[char]) -> Vec<&str> {
+        let mut wires: Vec<&str> = self
+            .nodes
+            .keys()
+            .map(String::as_str)
+            .filter(|wire| wire.starts_with(prefixes))
+            .collect();
+        wires.sort_by_key(|wire| (&wire[1..], &wire[..1]));
+        wires
+    }
+
+    /// A Graphviz graph of the network. Gates are shaped by their operator,
+    /// the inputs and outputs are ranked in bit order at either end, edges
+    /// carry their wire's entry in `values`, such as the ones from
+    /// [`Circuit::wire_values`], and `suspicious` wires are red.
+    pub fn to_dot(&self, values: &HashMap<String, u64>, suspicious: &HashSet<String>) -> String {
+        let mut dot = String::from("digraph network {\n    rankdir=LR;\n");
+        for (prefixes, rank) in [(&['x', 'y'][..], "source"), (&['z'][..], "sink")] {
+            let wires = self.in_bit_order(prefixes);
+            writeln!(dot, "    {{ rank={rank}; {}; }}", wires.join("; ")).unwrap();
+            if wires.len() > 1 {
+                writeln!(dot, "    {} [style=invis];", wires.join(" -> ")).unwrap();
+            }
+        }
+        for wire in self.in_bit_order(&['x', 'y']) {
+            writeln!(dot, "    {wire} [shape=circle];").unwrap();
+        }
+        let attributes = |wire: &str, mut list: Vec<String>| {
+            if suspicious.contains(wire) {
+                list.push("color=red".to_string());
+            }
+            if list.is_empty() {
+                String::new()
+            } else {
+                format!(" [{}]", list.join(", "))
+            }
+        };
+        for (output, op, _, _) in self.gates() {
+            let shape = format!("shape={}", op.dot_shape());
+            let label = format!("label=\"{output}\\n{}\"", op.name());
+            writeln!(
+                dot,
+                "    {output}{};",
+                attributes(output, vec![shape, label])
+            )
+            .unwrap();
+        }
+        for (output, _, a, b) in self.gates() {
+            for input in [a, b] {
+                let value = values.get(input).map(|value| format!("label=\"{value}\""));
+                let edge = attributes(input, value.into_iter().collect());
+                writeln!(dot, "    {input} -> {output}{edge};").unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// The same graph as [`Network::to_dot`], as a Mermaid flowchart. Node
+    /// ids get a `w_` prefix, since a wire may be named `end` or another
+    /// Mermaid keyword.
+    pub fn to_mermaid(
+        &self,
+        values: &HashMap<String, u64>,
+        suspicious: &HashSet<String>,
+    ) -> String {
+        let mut mermaid = String::from("flowchart LR\n");
+        for (name, prefixes) in [("inputs", &['x', 'y'][..]), ("outputs", &['z'][..])] {
+            writeln!(mermaid, "    subgraph {name}").unwrap();
+            for wire in self.in_bit_order(prefixes) {
+                writeln!(mermaid, "        w_{wire}").unwrap();
+            }
+            writeln!(mermaid, "    end").unwrap();
+        }
+        for wire in self.in_bit_order(&['x', 'y']) {
+            writeln!(mermaid, "    w_{wire}((\"{wire}\"))").unwrap();
+        }
+        for (output, op, _, _) in self.gates() {
+            let (open, close) = op.mermaid_shape();
+            let op = op.name();
+            writeln!(mermaid, "    w_{output}{open}\"{output} {op}\"{close}").unwrap();
+        }
+        for (output, _, a, b) in self.gates() {
+            for input in [a, b] {
+                match values.get(input) {
+                    Some(value) => writeln!(mermaid, "    w_{input} -->|{value}| w_{output}"),
+                    None => writeln!(mermaid, "    w_{input} --> w_{output}"),
+                }
+                .unwrap();
+            }
+        }
+        if !suspicious.is_empty() {
+            let mut wires: Vec<String> =
+                suspicious.iter().map(|wire| format!("w_{wire}")).collect();
+            wires.sort();
+            writeln!(
+                mermaid,
+                "    classDef suspicious stroke:red,stroke-width:3px"
+            )
+            .unwrap();
+            writeln!(mermaid, "    class {} suspicious", wires.join(",")).unwrap();
+        }
+        mermaid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL_EXAMPLE: &str = "\
+x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02
+";
+
+    const LARGE_EXAMPLE: &str = "\
+x00: 1
+x01: 0
+x02: 1
+x03: 1
+x04: 0
+y00: 1
+y01: 1
+y02: 1
+y03: 1
+y04: 1
+
+ntg XOR fgs -> mjb
+y02 OR x01 -> tnw
+kwq OR kpj -> z05
+x00 OR x03 -> fst
+tgd XOR rvg -> z01
+vdt OR tnw -> bfw
+bfw AND frj -> z10
+ffh OR nrd -> bqk
+y00 AND y03 -> djm
+y03 OR y00 -> psh
+bqk OR frj -> z08
+tnw OR fst -> frj
+gnj AND tgd -> z11
+bfw XOR mjb -> z00
+x03 OR x00 -> vdt
+gnj AND wpb -> z02
+x04 AND y00 -> kjc
+djm OR pbm -> qhw
+nrd AND vdt -> hwm
+kjc AND fst -> rvg
+y04 OR y02 -> fgs
+y01 AND x02 -> pbm
+ntg OR kjc -> kwq
+psh XOR fgs -> tgd
+qhw XOR tgd -> z09
+pbm OR djm -> kpj
+x03 XOR y03 -> ffh
+x00 XOR y04 -> ntg
+bfw OR bqk -> z06
+nrd XOR fgs -> wpb
+frj XOR qhw -> z04
+bqk OR frj -> z07
+y03 OR x01 -> nrd
+hwm AND bqk -> z03
+tgd XOR rvg -> z12
+tnw OR pbm -> gnj
+";
+
+    /// A ripple-carry adder for `bits`-bit numbers, with the outputs of each
+    /// pair in `swaps` exchanged.
+    fn adder(bits: usize, swaps: &[(&str, &str)]) -> String {
+        let mut text = String::new();
+        for bit in 0..bits {
+            text += &format!("x{bit:02}: 0\ny{bit:02}: 0\n");
+        }
+        text += "\nx00 XOR y00 -> z00\nx00 AND y00 -> c00\n";
+        for bit in 1..bits {
+            let carry_in = format!("c{:02}", bit - 1);
+            let carry_out = if bit == bits - 1 {
+                format!("z{bits:02}")
+            } else {
+                format!("c{bit:02}")
+            };
+            text += &format!(
+                "x{bit:02} XOR y{bit:02} -> s{bit:02}\n\
+                 y{bit:02} AND x{bit:02} -> a{bit:02}\n\
+                 {carry_in} XOR s{bit:02} -> z{bit:02}\n\
+                 s{bit:02} AND {carry_in} -> b{bit:02}\n\
+                 a{bit:02} OR b{bit:02} -> {carry_out}\n"
+            );
+        }
+        for (a, b) in swaps {
+            text = text
+                .replace(&format!("-> {a}\n"), "-> SWAP\n")
+                .replace(&format!("-> {b}\n"), &format!("-> {a}\n"))
+                .replace("-> SWAP\n", &format!("-> {b}\n"));
+        }
+        text
+    }
+
+    #[test]
+    fn intact_adders_need_no_swaps() {
+        let network = parse_input(&adder(8, &[])).unwrap();
+        assert_eq!(network.find_swaps(), Ok(vec![]));
+        assert_eq!(network.compile().unwrap().set_input(200, 100), 300);
+    }
+
+    #[test]
+    fn swapped_outputs_are_found() {
+        let swaps = [
+            ("s01", "a01"),
+            ("z02", "b02"),
+            ("z03", "c03"),
+            ("a05", "z05"),
+        ];
+        let network = parse_input(&adder(8, &swaps)).unwrap();
+        assert_ne!(network.compile().unwrap().set_input(200, 100), 300);
+        assert_eq!(
+            part_two(&network),
+            Ok("a01,a05,b02,c03,s01,z02,z03,z05".to_string())
+        );
+    }
+
+    #[test]
+    fn other_circuits_are_not_adders() {
+        let network = parse_input(SMALL_EXAMPLE).unwrap();
+        assert!(matches!(
+            network.find_swaps(),
+            Err(AdderError::MissingGate(_))
+        ));
+    }
+
+    #[test]
+    fn swaps_onto_missing_wires_are_errors() {
+        let no_last_carry = parse_input("x00: 1\ny00: 0\n\nx00 XOR y00 -> z00\nx00 AND y00 -> c\n");
+        let network = no_last_carry.unwrap();
+        assert_eq!(
+            part_two(&network),
+            Err(AdderError::MissingWire("z01".to_string()))
+        );
+        assert!(network.suspicious_wires().is_empty());
+        let no_z00 = parse_input("x00: 1\ny00: 0\n\nx00 XOR y00 -> abc\nx00 AND y00 -> z01\n");
+        let network = no_z00.unwrap();
+        assert_eq!(
+            part_two(&network),
+            Err(AdderError::MissingWire("z00".to_string()))
+        );
+        assert!(network.suspicious_wires().is_empty());
+    }
+
+    #[test]
+    fn renders_show_values_and_suspicious_wires() {
+        let network = parse_input(SMALL_EXAMPLE).unwrap();
+        let values = network.compile().unwrap().wire_values();
+        let suspicious = HashSet::from(["z01".to_string()]);
+        let dot = network.to_dot(&values, &suspicious);
+        assert!(dot.contains("{ rank=source; x00; y00; x01; y01; x02; y02; }"));
+        assert!(dot.contains("z00 -> z01 -> z02 [style=invis];"));
+        assert!(dot.contains("z01 [shape=diamond, label=\"z01\\nXOR\", color=red];"));
+        assert!(dot.contains("x00 -> z00 [label=\"1\"];"));
+        assert!(dot.contains("x01 -> z01 [label=\"1\"];"));
+        assert!(
+            network
+                .to_dot(&HashMap::new(), &suspicious)
+                .contains("x01 -> z01;")
+        );
+        let mermaid = network.to_mermaid(&values, &suspicious);
+        assert!(mermaid.contains("        w_x00\n"));
+        assert!(mermaid.contains("    w_x00((\"x00\"))\n"));
+        assert!(mermaid.contains("    w_z01{{\"z01 XOR\"}}\n"));
+        assert!(mermaid.contains("    w_y00 -->|0| w_z00\n"));
+        assert!(mermaid.contains("    class w_z01 suspicious\n"));
+    }
+
+    #[test]
+    fn mermaid_ids_never_clash_with_keywords() {
+        let network =
+            parse_input("x00: 1\ny00: 0\n\nx00 OR y00 -> end\nend XOR x00 -> z00\n").unwrap();
+        let mermaid = network.to_mermaid(&HashMap::new(), &HashSet::new());
+        assert!(mermaid.contains("    w_end(\"end OR\")\n"));
+        assert!(mermaid.contains("    w_end --> w_z00\n"));
+        let ends = mermaid.lines().filter(|line| line.trim() == "end").count();
+        assert_eq!(ends, 2);
+    }
+
+    #[test]
+    fn circuits_add_64_pairs_at_once() {
+        let circuit = parse_input(&adder(8, &[])).unwrap().compile().unwrap();
+        let pairs: Vec<(u64, u64)> = (0..100).map(|i| (i * 37 % 256, i * 101 % 256)).collect();
+        let sums: Vec<u64> = pairs.iter().map(|(x, y)| x + y).collect();
+        assert_eq!(circuit.set_inputs(&pairs), sums);
+        assert_eq!(circuit.evaluate_sliced(&[0b01], &[0b11])[..2], [0b10, 0b01]);
+    }
+
+    #[test]
+    fn cyclic_netlists_are_rejected() {
+        let network =
+            parse_input("x00: 1\ny00: 0\n\nx00 AND b -> a\na OR y00 -> b\nb XOR x00 -> z00\n")
+                .unwrap();
+        let cycle = network.compile().unwrap_err();
+        let expected = Cycle(vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(cycle, CircuitError::Cycle(expected));
+        assert!(part_one(&network).is_err());
+    }
+
+    #[test]
+    fn buses_wider_than_a_u64_are_rejected() {
+        let network = parse_input(&adder(64, &[])).unwrap();
+        let too_wide = CircuitError::TooWide {
+            bus: 'x',
+            wires: 64,
+            max: 63,
+        };
+        assert_eq!(network.compile().unwrap_err(), too_wide.clone());
+        assert_eq!(network.find_swaps(), Err(AdderError::Circuit(too_wide)));
+    }
+
+    #[test]
+    fn part_one_examples() {
+        assert_eq!(part_one(&parse_input(SMALL_EXAMPLE).unwrap()), Ok(4));
+        assert_eq!(part_one(&parse_input(LARGE_EXAMPLE).unwrap()), Ok(2024));
+    }
+}
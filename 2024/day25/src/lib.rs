@@ -0,0 +1,127 @@
+use aoc_common::{Grid, ParseError, Point, sections};
+use itertools::Itertools;
+use std::iter::zip;
+
+type Seq = Vec<usize>;
+
+pub struct LocksAndKeys {
+    locks: Vec<Seq>,
+    keys: Vec<Seq>,
+}
+
+const ROWS: usize = 7;
+const COLS: usize = 5;
+
+pub fn parse_input(input: &str) -> Result<LocksAndKeys, ParseError> {
+    let mut locks: Vec<Seq> = Vec::new();
+    let mut keys: Vec<Seq> = Vec::new();
+    for schematic in sections(input) {
+        if let Some(extra_line) = schematic.get(ROWS) {
+            return Err(extra_line.error("a blank line"));
+        }
+        if schematic.len() < ROWS {
+            let last_line = schematic[schematic.len() - 1];
+            return Err(last_line.missing_after(format!("{ROWS} rows")));
+        }
+        let first_line = schematic[0];
+        let grid = Grid::parse_lines_with(schematic, |c, span| match c {
+            '#' | '.' => Ok(c),
+            _ => Err(span.error("`#` or `.`")),
+        })?;
+        if grid.cols() != COLS {
+            return Err(first_line.error(format!("{COLS} columns")));
+        }
+        let seq: Seq = (0..COLS)
+            .map(|column| {
+                (0..ROWS - 1)
+                    .map(|row| grid[Point::new(row as i32, column as i32)])
+                    .filter(|&c| c == '#')
+                    .count()
+            })
+            .collect();
+        match grid[Point::new(0, 0)] {
+            '.' => {
+                keys.push(seq);
+            }
+            _ => {
+                locks.push(seq);
+            }
+        }
+    }
+    Ok(LocksAndKeys { locks, keys })
+}
+
+fn compatible(lock: &Seq, key: &Seq) -> bool {
+    for (lock_elem, key_elem) in zip(lock, key) {
+        if lock_elem + key_elem > 6 {
+            return false;
+        }
+    }
+    true
+}
+
+pub fn part_one(locks_and_keys: &LocksAndKeys) -> i32 {
+    let mut res = 0;
+    for (lock, key) in locks_and_keys
+        .locks
+        .iter()
+        .cartesian_product(locks_and_keys.keys.iter())
+    {
+        if compatible(lock, key) {
+            res += 1;
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 3);
+    }
+}
@@ -0,0 +1,72 @@
+use aoc_common::{ParseError, Span, non_empty_lines};
+fn parse_delta(line: Span) -> Result<i32, ParseError> {
+    let (sign, rest) = match (line.strip_prefix("L"), line.strip_prefix("R")) {
+        (Ok(rest), _) => (-1, rest),
+        (_, Ok(rest)) => (1, rest),
+        _ => return Err(line.error("`L` or `R`")),
+    };
+    Ok(sign * rest.parse::<i32>()?)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<i32>, ParseError> {
+    non_empty_lines(input).map(parse_delta).collect()
+}
+
+pub fn part_one(deltas: &[i32]) -> i32 {
+    let mut position: i32 = 50;
+    let mut res = 0;
+    for &delta in deltas {
+        position += delta;
+        if position % 100 == 0 {
+            res += 1;
+        }
+    }
+    res
+}
+
+pub fn part_two(deltas: &[i32]) -> i32 {
+    let mut position: i32 = 50;
+    let mut res = 0;
+    for &delta in deltas {
+        let (a, ar) = (position.div_euclid(100), position.rem_euclid(100));
+        position += delta;
+        let (b, br) = (position.div_euclid(100), position.rem_euclid(100));
+        let mut d = (a - b).abs();
+        if ar == 0 && delta < 0 && d > 0 {
+            d -= 1;
+        }
+        if br == 0 && delta < 0 {
+            d += 1;
+        }
+        res += d;
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 3);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 6);
+    }
+}
@@ -0,0 +1,237 @@
+use aoc_common::{IntervalSet, ParseError, lines};
+use std::{collections::BTreeSet, num::TryFromIntError, ops::RangeInclusive};
+
+/// The listed ranges. A number in several overlapping ranges counts once
+/// for each of them, so the parts of a range that earlier ranges already
+/// cover are kept apart from the merged set, to be counted again.
+#[derive(Debug, Default)]
+pub struct Ranges {
+    covered: IntervalSet<u64>,
+    repeated: Vec<RangeInclusive<u64>>,
+}
+
+impl Ranges {
+    fn insert(&mut self, range: RangeInclusive<u64>) {
+        let new: IntervalSet<u64> = [range.clone()].into_iter().collect();
+        self.repeated.extend(self.covered.intersection(&new).iter());
+        self.covered.insert(range);
+    }
+
+    /// Spans that together hold each number as many times as it was listed.
+    fn iter(&self) -> impl Iterator<Item = RangeInclusive<u64>> + '_ {
+        self.covered.iter().chain(self.repeated.iter().cloned())
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Ranges, ParseError> {
+    let line = lines(input)
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "a list of ranges"))?;
+
+    let mut ranges = Ranges::default();
+    for part in line.split(",") {
+        let [start, end] = part.split_exact("-")?;
+        ranges.insert(start.parse::<u64>()?..=end.parse::<u64>()?);
+    }
+    Ok(ranges)
+}
+
+/// Which repeated-block numbers to look for: a block of digits, without a
+/// leading zero, written out several times in a row.
+#[derive(Debug, Clone, Copy)]
+pub enum Repeats {
+    Exactly(u32),
+    AtLeast(u32),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Tally {
+    pub count: u64,
+    pub sum: u128,
+}
+
+impl Tally {
+    fn add(self, other: Tally) -> Tally {
+        Tally {
+            count: self.count + other.count,
+            sum: self.sum + other.sum,
+        }
+    }
+
+    fn sub(self, other: Tally) -> Tally {
+        Tally {
+            count: self.count - other.count,
+            sum: self.sum - other.sum,
+        }
+    }
+}
+
+fn pow10(exponent: u32) -> u128 {
+    10u128.pow(exponent)
+}
+
+fn digits(n: u64) -> u32 {
+    n.checked_ilog10().map_or(1, |log| log + 1)
+}
+
+/// The `len`-digit numbers in `lo..=hi` made of a `period`-digit block
+/// repeated. They are the multiples of `100..0100..01` by such a block.
+fn with_period(lo: u64, hi: u64, len: u32, period: u32) -> Tally {
+    let repunit = (pow10(len) - 1) / (pow10(period) - 1);
+    let lo = (lo as u128).max(pow10(len - 1));
+    let hi = (hi as u128).min(pow10(len) - 1);
+    let first_block = lo.div_ceil(repunit).max(pow10(period - 1));
+    let last_block = (hi / repunit).min(pow10(period) - 1);
+    if lo > hi || first_block > last_block {
+        return Tally::default();
+    }
+    let count = last_block - first_block + 1;
+    Tally {
+        count: count as u64,
+        sum: repunit * (first_block + last_block) * count / 2,
+    }
+}
+
+/// The periods a `len`-digit number may repeat with.
+fn periods(len: u32, repeats: Repeats) -> Vec<u32> {
+    (1..len)
+        .filter(|&period| len.is_multiple_of(period))
+        .filter(|&period| match repeats {
+            Repeats::Exactly(times) => len / period == times,
+            Repeats::AtLeast(times) => len / period >= times,
+        })
+        .collect()
+}
+
+/// Counts and sums the repeated-block numbers in `range` without visiting
+/// them, so the width of the range doesn't matter.
+pub fn tally(range: RangeInclusive<u64>, repeats: Repeats) -> Tally {
+    let (lo, hi) = range.into_inner();
+    if lo > hi {
+        return Tally::default();
+    }
+    let mut total = Tally::default();
+    for len in digits(lo)..=digits(hi) {
+        // A number repeating a block of `period` digits also repeats every
+        // longer block that `period` divides, so count each number only for
+        // its shortest period. The allowed periods are closed under taking
+        // divisors, so every shorter period is tallied first.
+        let periods = periods(len, repeats);
+        let mut shortest: Vec<Tally> = Vec::with_capacity(periods.len());
+        for (i, &period) in periods.iter().enumerate() {
+            let longer_period = (0..i)
+                .filter(|&j| period.is_multiple_of(periods[j]))
+                .fold(Tally::default(), |tally, j| tally.add(shortest[j]));
+            shortest.push(with_period(lo, hi, len, period).sub(longer_period));
+        }
+        total = shortest.into_iter().fold(total, Tally::add);
+    }
+    total
+}
+
+/// Every repeated-block number in `range`, in increasing order.
+pub fn repeated_numbers(range: RangeInclusive<u64>, repeats: Repeats) -> Vec<u64> {
+    let (lo, hi) = range.into_inner();
+    let mut numbers = BTreeSet::new();
+    for len in digits(lo)..=digits(hi.max(lo)) {
+        for period in periods(len, repeats) {
+            let repunit = (pow10(len) - 1) / (pow10(period) - 1);
+            for block in pow10(period - 1)..pow10(period) {
+                let number = block * repunit;
+                if number > hi as u128 {
+                    break;
+                }
+                if number >= lo as u128 {
+                    numbers.insert(number as u64);
+                }
+            }
+        }
+    }
+    numbers.into_iter().collect()
+}
+
+fn solve(ranges: &Ranges, repeats: Repeats) -> Result<u64, TryFromIntError> {
+    let sum: u128 = ranges.iter().map(|range| tally(range, repeats).sum).sum();
+    u64::try_from(sum)
+}
+
+pub fn part_one(ranges: &Ranges) -> Result<u64, TryFromIntError> {
+    solve(ranges, Repeats::Exactly(2))
+}
+
+pub fn part_two(ranges: &Ranges) -> Result<u64, TryFromIntError> {
+    solve(ranges, Repeats::AtLeast(2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124
+";
+
+    fn is_periodic(v: &str, d: usize) -> bool {
+        v.len().is_multiple_of(d)
+            && v.as_bytes()
+                .chunks(d)
+                .all(|chunk| chunk == &v.as_bytes()[..d])
+    }
+
+    #[test]
+    fn tallies_match_brute_force() {
+        for (range, repeats) in [
+            (1..=200_000, Repeats::Exactly(2)),
+            (1..=200_000, Repeats::AtLeast(2)),
+            (95..=1_000_000, Repeats::AtLeast(3)),
+        ] {
+            let expected: Vec<u64> = range
+                .clone()
+                .filter(|x| {
+                    let s = x.to_string();
+                    (1..s.len()).any(|d| match repeats {
+                        Repeats::Exactly(times) => {
+                            s.len() == d * times as usize && is_periodic(&s, d)
+                        }
+                        Repeats::AtLeast(times) => {
+                            s.len() >= d * times as usize && is_periodic(&s, d)
+                        }
+                    })
+                })
+                .collect();
+            assert_eq!(repeated_numbers(range.clone(), repeats), expected);
+            let tally = tally(range, repeats);
+            assert_eq!(tally.count, expected.len() as u64);
+            assert_eq!(tally.sum, expected.iter().map(|&x| x as u128).sum());
+        }
+    }
+
+    #[test]
+    fn wide_ranges_are_tallied() {
+        let tally = tally(1..=u64::MAX, Repeats::Exactly(2));
+        // Every block of up to nine digits written twice, and the ten-digit
+        // blocks up to the first half of `u64::MAX`.
+        assert_eq!(tally.count, 999_999_999 + (1_844_674_407 - 999_999_999));
+    }
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), Ok(1227775554));
+    }
+
+    #[test]
+    fn overlapping_ranges_count_twice() {
+        assert_eq!(part_one(&parse_input("11-22,20-33\n").unwrap()), Ok(88));
+        assert_eq!(
+            part_one(&parse_input("11-33,20-25,1-99\n").unwrap()),
+            Ok(583)
+        );
+        let everything = format!("1-{}\n", u64::MAX);
+        assert!(part_two(&parse_input(&everything).unwrap()).is_err());
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), Ok(4174379265));
+    }
+}
@@ -0,0 +1,65 @@
+use aoc_common::{ParseError, non_empty_lines};
+use std::cmp::max;
+
+fn solve(banks: &[Vec<u64>], num_digits: usize) -> u64 {
+    let mut res = 0;
+    for bank in banks {
+        let mut bests: Vec<u64> = vec![0; num_digits + 1];
+        for &element in bank.iter().rev() {
+            for i in (1..=num_digits).rev() {
+                if bests[i - 1] > 0 || i == 1 {
+                    bests[i] = max(
+                        bests[i],
+                        element * (10_u64).pow((i - 1).try_into().unwrap()) + bests[i - 1],
+                    );
+                }
+            }
+        }
+        res += bests[num_digits]
+    }
+    res
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Vec<u64>>, ParseError> {
+    non_empty_lines(input)
+        .map(|s| {
+            s.chars()
+                .map(|(c, span)| {
+                    c.to_digit(10)
+                        .map(u64::from)
+                        .ok_or_else(|| span.error("a digit"))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn part_one(banks: &[Vec<u64>]) -> u64 {
+    solve(banks, 2)
+}
+
+pub fn part_two(banks: &[Vec<u64>]) -> u64 {
+    solve(banks, 12)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+987654321111111
+811111111111119
+234234234234278
+818181911112111
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 357);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 3121910778619);
+    }
+}
@@ -0,0 +1,73 @@
+use aoc_common::{Grid, ParseError, Point};
+use std::collections::{HashSet, VecDeque};
+
+pub fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse_from_str(input)
+}
+
+fn is_removable(point: Point, grid: &Grid<char>) -> bool {
+    if grid[point] != '@' {
+        return false;
+    }
+    grid.neighbours8(point)
+        .filter(|&neighbour| grid[neighbour] == '@')
+        .count()
+        < 4
+}
+
+pub fn part_one(grid: &Grid<char>) -> usize {
+    grid.points()
+        .filter(|&point| is_removable(point, grid))
+        .count()
+}
+
+pub fn part_two(grid: &Grid<char>) -> usize {
+    let mut grid = grid.clone();
+    let mut q: VecDeque<Point> = VecDeque::new();
+    let mut added: HashSet<Point> = HashSet::new();
+    for point in grid.points() {
+        if is_removable(point, &grid) {
+            q.push_back(point);
+            added.insert(point);
+        }
+    }
+    while let Some(point) = q.pop_front() {
+        grid[point] = '.';
+        let neighbours: Vec<Point> = grid.neighbours8(point).collect();
+        for neighbour in neighbours {
+            if is_removable(neighbour, &grid) && !added.contains(&neighbour) {
+                q.push_back(neighbour);
+                added.insert(neighbour);
+            }
+        }
+    }
+    added.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 13);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 43);
+    }
+}
@@ -0,0 +1,65 @@
+use aoc_common::{IntervalSet, ParseError, split_sections};
+use std::num::TryFromIntError;
+
+type Ranges = IntervalSet<u64>;
+type Queries = Vec<u64>;
+
+pub type Input = (Ranges, Queries);
+
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let [ranges, queries] = split_sections(input)?;
+    let ranges = ranges
+        .iter()
+        .map(|line| {
+            let (l, r) = line.split_once("-")?;
+            Ok(l.parse::<u64>()?..=r.parse::<u64>()?)
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    let queries = queries
+        .iter()
+        .map(|line| line.parse::<u64>())
+        .collect::<Result<_, _>>()?;
+
+    Ok((ranges, queries))
+}
+
+pub fn part_one((ranges, queries): &Input) -> usize {
+    queries
+        .iter()
+        .filter(|&&query| ranges.contains(query))
+        .count()
+}
+
+pub fn part_two((ranges, _): &Input) -> Result<i128, TryFromIntError> {
+    i128::try_from(ranges.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 3);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), Ok(14));
+    }
+}
@@ -0,0 +1,193 @@
+use aoc_common::{ParseError, Span, split_sections};
+
+#[derive(Copy, Clone, Debug)]
+enum Op {
+    Plus,
+    Mul,
+}
+
+impl Op {
+    fn apply(self, nums: impl IntoIterator<Item = u64>) -> u64 {
+        match self {
+            Op::Plus => nums.into_iter().sum(),
+            Op::Mul => nums.into_iter().product(),
+        }
+    }
+}
+
+type Problem = (Op, Vec<u64>);
+
+pub struct Worksheet {
+    nums: Vec<Vec<u64>>,
+    ops: Vec<Op>,
+    /// The problems as part two reads them, a number per column.
+    columns: Vec<Problem>,
+}
+
+/// Rows of numbers followed by a row of operators, one column per problem.
+pub fn parse_input(input: &str) -> Result<Worksheet, ParseError> {
+    let [lines] = split_sections(input)?;
+    let (&ops_line, num_lines) = lines.split_last().expect("sections aren't empty");
+    if num_lines.is_empty() {
+        return Err(ops_line.error("a row of numbers"));
+    }
+    let ops: Vec<Op> = ops_line
+        .split_whitespace()
+        .map(|part| match part.text() {
+            "*" => Ok(Op::Mul),
+            "+" => Ok(Op::Plus),
+            _ => Err(part.error("`*` or `+`")),
+        })
+        .collect::<Result<_, _>>()?;
+    let mut nums: Vec<Vec<u64>> = Vec::new();
+    for line in num_lines {
+        if let Some((_, span)) = line.chars().find(|&(c, _)| c != ' ' && !c.is_ascii_digit()) {
+            return Err(span.error("a digit or a space"));
+        }
+        let row: Vec<u64> = line
+            .split_whitespace()
+            .map(|part| part.parse())
+            .collect::<Result<_, _>>()?;
+        if row.len() != ops.len() {
+            return Err(line.end().error(format!("{} numbers", ops.len())));
+        }
+        nums.push(row);
+    }
+    let columns = parse_columns(&lines)?;
+    Ok(Worksheet { nums, ops, columns })
+}
+
+/// Reads the problems down the columns of `lines`, whose last line holds
+/// the operators. Problems are separated by columns of spaces, and each
+/// needs exactly one operator and at least one number.
+fn parse_columns(lines: &[Span]) -> Result<Vec<Problem>, ParseError> {
+    let rows: Vec<Vec<(char, Span)>> = lines.iter().map(|line| line.chars().collect()).collect();
+    let (ops_row, num_rows) = rows.split_last().expect("there is a row of operators");
+    let ops_line = lines[lines.len() - 1];
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let cell = |row: &[(char, Span)], col: usize| row.get(col).map(|&(c, _)| c);
+    let is_blank = |col: usize| {
+        rows.iter()
+            .all(|row| cell(row, col).is_none_or(|c| c == ' '))
+    };
+
+    let mut problems = Vec::new();
+    let mut col = 0;
+    while col < width {
+        if is_blank(col) {
+            col += 1;
+            continue;
+        }
+        let start = col;
+        while col < width && !is_blank(col) {
+            col += 1;
+        }
+        let ops: Vec<(Op, Span)> = ops_row[start.min(ops_row.len())..col.min(ops_row.len())]
+            .iter()
+            .filter_map(|&(c, span)| match c {
+                '*' => Some((Op::Mul, span)),
+                '+' => Some((Op::Plus, span)),
+                _ => None,
+            })
+            .collect();
+        let op = match ops[..] {
+            [(op, _)] => op,
+            [] => {
+                let span = ops_row.get(start).map_or(ops_line.end(), |&(_, span)| span);
+                return Err(span.error("an operator under every problem"));
+            }
+            [_, (_, span), ..] => return Err(span.error("one operator per problem")),
+        };
+        let mut nums = Vec::new();
+        for c in start..col {
+            let digits: Vec<(char, Span)> = num_rows
+                .iter()
+                .filter_map(|row| row.get(c).copied())
+                .filter(|&(c, _)| c != ' ')
+                .collect();
+            let Some(&(_, first)) = digits.first() else {
+                continue;
+            };
+            let number: String = digits.iter().map(|&(c, _)| c).collect();
+            nums.push(
+                number
+                    .parse()
+                    .map_err(|_| first.error("a column of digits that fits in a `u64`"))?,
+            );
+        }
+        if nums.is_empty() {
+            let (_, span) = ops_row[start..col]
+                .iter()
+                .find(|&&(c, _)| c != ' ')
+                .expect("the problem has its operator");
+            return Err(span.error("numbers above every operator"));
+        }
+        problems.push((op, nums));
+    }
+    Ok(problems)
+}
+
+pub fn part_one(worksheet: &Worksheet) -> u64 {
+    let (nums, ops) = (&worksheet.nums, &worksheet.ops);
+    ops.iter()
+        .enumerate()
+        .map(|(idx, op)| op.apply(nums.iter().map(|row| row[idx])))
+        .sum()
+}
+
+pub fn part_two(worksheet: &Worksheet) -> u64 {
+    worksheet
+        .columns
+        .iter()
+        .map(|(op, nums)| op.apply(nums.iter().copied()))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+123 328  51 64
+ 45 64  387 23
+  6 98  215 314
+*   +   *   +
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 4277556);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 3263827);
+    }
+
+    #[test]
+    fn problems_need_their_operator_and_numbers() {
+        let err = |input: &str| {
+            let err = parse_input(input).err().unwrap();
+            (err.line, err.column, err.expected)
+        };
+        let misaligned = "12 34\n*     +\n";
+        assert_eq!(
+            err(misaligned),
+            (2, 4, "an operator under every problem".into())
+        );
+        let shared = "1234 5\n* +\n";
+        assert_eq!(err(shared), (2, 3, "one operator per problem".into()));
+        let no_numbers = "1   2\n* +\n";
+        assert_eq!(
+            err(no_numbers),
+            (2, 3, "numbers above every operator".into())
+        );
+        let trailing = "1 2\n* +\n\n3\n";
+        assert_eq!(err(trailing), (4, 1, "end of input".into()));
+        let wide = "9\n".repeat(21) + "+\n";
+        assert_eq!(
+            err(&wide),
+            (1, 1, "a column of digits that fits in a `u64`".into())
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use aoc_common::{Grid, ParseError, Point, split_sections};
+
+pub fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    let [rows] = split_sections(input)?;
+    let first_row = rows[0];
+    if !first_row.text().contains('S') {
+        return Err(first_row.end().error("a start (`S`)"));
+    }
+    Grid::parse_lines_with(rows, |c, _| Ok(c))
+}
+
+fn solve(grid: &mut Grid<char>) -> (i32, u64) {
+    let (rows, cols) = (grid.rows(), grid.cols());
+    let mut res = 0;
+    let mut dp = vec![vec![0u64; cols]; rows];
+    dp[0][grid.row(0).iter().position(|e| *e == 'S').unwrap()] = 1;
+    for row in 1..rows {
+        for col in 0..cols {
+            let point = Point::new(row as i32, col as i32);
+            let above = grid[point + Point::new(-1, 0)];
+            match grid[point] {
+                '^' if "S|".contains(above) => {
+                    res += 1;
+                    if col >= 1 {
+                        grid[point + Point::new(0, -1)] = '|';
+                        dp[row][col - 1] += dp[row - 1][col];
+                    }
+                    if col < cols - 1 {
+                        grid[point + Point::new(0, 1)] = '|';
+                        dp[row][col + 1] += dp[row - 1][col];
+                    }
+                }
+                '.' => {
+                    dp[row][col] += dp[row - 1][col];
+                    if "|S".contains(above) {
+                        grid[point] = '|';
+                    }
+                }
+                _ => {
+                    dp[row][col] += dp[row - 1][col];
+                }
+            }
+        }
+    }
+    (res, dp.last().unwrap().iter().sum())
+}
+
+pub fn part_one(grid: &Grid<char>) -> i32 {
+    solve(&mut grid.clone()).0
+}
+
+pub fn part_two(grid: &Grid<char>) -> u64 {
+    solve(&mut grid.clone()).1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 21);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 40);
+    }
+
+    #[test]
+    fn content_after_the_grid_is_an_error() {
+        let err = parse_input("..S..\n.....\n\n..^..\n").err().unwrap();
+        assert_eq!((err.line, err.column), (4, 1));
+        assert_eq!(err.expected, "end of input");
+    }
+}
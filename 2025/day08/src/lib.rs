@@ -0,0 +1,171 @@
+use aoc_common::{DisjointSet, ParseError, non_empty_lines};
+use serde::Deserialize;
+use std::fmt;
+
+type Point = (i64, i64, i64);
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// How many of the closest pairs part one connects.
+    pub num_merges: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { num_merges: 1000 }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Point>, ParseError> {
+    non_empty_lines(input)
+        .map(|line| {
+            let [x, y, z] = line.split_exact(",")?;
+            Ok((x.parse()?, y.parse()?, z.parse()?))
+        })
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CircuitError {
+    /// `num_merges` asks for more connections than there are pairs of boxes.
+    TooManyMerges { num_merges: usize, pairs: usize },
+    /// Part one multiplies the sizes of the three largest circuits.
+    TooFewCircuits(usize),
+    /// A single box is never connected to anything.
+    TooFewBoxes,
+}
+
+impl fmt::Display for CircuitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CircuitError::TooManyMerges { num_merges, pairs } => {
+                write!(
+                    f,
+                    "num_merges is {num_merges}, but there are only {pairs} pairs"
+                )
+            }
+            CircuitError::TooFewCircuits(count) => {
+                write!(f, "expected at least 3 circuits, found {count}")
+            }
+            CircuitError::TooFewBoxes => write!(f, "expected at least 2 junction boxes"),
+        }
+    }
+}
+
+impl std::error::Error for CircuitError {}
+
+/// Every pair of boxes, closest first.
+fn closest_pairs(points: &[Point]) -> Vec<(i64, usize, usize)> {
+    let mut dists = Vec::new();
+    for i in 0..points.len() {
+        for j in i + 1..points.len() {
+            let (x, y, z) = (
+                points[i].0 - points[j].0,
+                points[i].1 - points[j].1,
+                points[i].2 - points[j].2,
+            );
+            dists.push((x * x + y * y + z * z, i, j));
+        }
+    }
+    dists.sort();
+    dists
+}
+
+pub fn part_one(points: &[Point], params: &Params) -> Result<u64, CircuitError> {
+    let pairs = closest_pairs(points);
+    let num_merges = params.num_merges;
+    let merges = pairs.get(..num_merges).ok_or(CircuitError::TooManyMerges {
+        num_merges,
+        pairs: pairs.len(),
+    })?;
+    let mut circuits = DisjointSet::new(points.len());
+    for &(_, i, j) in merges {
+        circuits.union(i, j);
+    }
+    let mut sizes: Vec<u64> = circuits.component_sizes().map(|size| size as u64).collect();
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    let largest = sizes
+        .get(..3)
+        .ok_or(CircuitError::TooFewCircuits(sizes.len()))?;
+    Ok(largest.iter().product())
+}
+
+/// The last connection joins every box into one circuit.
+pub fn part_two(points: &[Point], _params: &Params) -> Result<i64, CircuitError> {
+    let mut circuits = DisjointSet::new(points.len());
+    for (_, i, j) in closest_pairs(points) {
+        circuits.union(i, j);
+        if circuits.component_count() == 1 {
+            return Ok(points[i].0 * points[j].0);
+        }
+    }
+    Err(CircuitError::TooFewBoxes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689
+";
+
+    const PARAMS: Params = Params { num_merges: 10 };
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap(), &PARAMS), Ok(40));
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap(), &PARAMS), Ok(25272));
+    }
+
+    #[test]
+    fn blank_lines_are_skipped() {
+        let points = parse_input("1,2,3\n\n4,5,6\n\n").unwrap();
+        assert_eq!(points, [(1, 2, 3), (4, 5, 6)]);
+    }
+
+    #[test]
+    fn bad_params_are_errors() {
+        let points = parse_input(EXAMPLE).unwrap();
+        assert_eq!(part_one(&points, &Params { num_merges: 0 }), Ok(1));
+        assert_eq!(
+            part_one(&points, &Params { num_merges: 191 }),
+            Err(CircuitError::TooManyMerges {
+                num_merges: 191,
+                pairs: 190
+            })
+        );
+        assert_eq!(
+            part_one(&points, &Params { num_merges: 190 }),
+            Err(CircuitError::TooFewCircuits(1))
+        );
+        assert_eq!(
+            part_two(&points[..1], &PARAMS),
+            Err(CircuitError::TooFewBoxes)
+        );
+    }
+}
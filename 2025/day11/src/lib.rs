@@ -0,0 +1,134 @@
+use aoc_common::{
+    ParseError, non_empty_lines,
+    toposort::{Cycle, Dag, TieBreak},
+};
+use std::collections::HashMap;
+
+pub type Graph = HashMap<String, Vec<String>>;
+
+pub fn parse_input(input: &str) -> Result<Graph, ParseError> {
+    non_empty_lines(input)
+        .map(|line| {
+            let (src, dests) = line.split_once(": ")?;
+            Ok((
+                src.text().to_string(),
+                dests
+                    .split_whitespace()
+                    .map(|s| s.text().to_owned())
+                    .collect(),
+            ))
+        })
+        .collect()
+}
+
+fn topological_order(graph: &Graph) -> Result<Vec<&str>, Cycle<String>> {
+    let mut dag = Dag::new();
+    // Sorted, so that the same cycle is reported every time.
+    let mut srcs: Vec<_> = graph.iter().collect();
+    srcs.sort();
+    for (src, dests) in srcs {
+        dag.add_node(src.as_str());
+        for dest in dests {
+            dag.add_edge(src.as_str(), dest.as_str());
+        }
+    }
+    dag.kahn(TieBreak::Lexicographic)
+        .map_err(|Cycle(devices)| Cycle(devices.into_iter().map(str::to_string).collect()))
+}
+
+fn solve(graph: &HashMap<String, Vec<String>>, order: &[&str], start_node: &str) -> (u64, u64) {
+    let mut dp: HashMap<(&str, u8), u64> = HashMap::new();
+    dp.insert((start_node, 0), 1);
+    for &v in order {
+        for vis_cnt in 0..=2_u8 {
+            let cnt = *dp.get(&(v, vis_cnt)).unwrap_or(&0u64);
+            if let Some(dests) = graph.get(v) {
+                for dest in dests {
+                    let new_vis_cnt = if ["fft", "dac"].contains(&dest.as_str()) {
+                        vis_cnt + 1
+                    } else {
+                        vis_cnt
+                    };
+                    *dp.entry((dest, new_vis_cnt)).or_default() += cnt;
+                }
+            }
+        }
+    }
+    let mut part_one = 0;
+    let mut part_two = 0;
+    for vis_cnt in 0..=2 {
+        if let Some(cnt) = dp.get(&("out", vis_cnt)) {
+            part_one += cnt;
+            if vis_cnt == 2 {
+                part_two += cnt;
+            }
+        }
+    }
+    (part_one, part_two)
+}
+
+pub fn part_one(graph: &Graph) -> Result<u64, Cycle<String>> {
+    Ok(solve(graph, &topological_order(graph)?, "you").0)
+}
+
+pub fn part_two(graph: &Graph) -> Result<u64, Cycle<String>> {
+    Ok(solve(graph, &topological_order(graph)?, "svr").1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PART_ONE_EXAMPLE: &str = "\
+aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out
+";
+
+    const PART_TWO_EXAMPLE: &str = "\
+svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out
+";
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(PART_ONE_EXAMPLE).unwrap()), Ok(5));
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(PART_TWO_EXAMPLE).unwrap()), Ok(2));
+    }
+
+    #[test]
+    fn blank_lines_are_skipped() {
+        let graph = parse_input("you: aaa\n\naaa: out\n").unwrap();
+        assert_eq!(part_one(&graph), Ok(1));
+    }
+
+    #[test]
+    fn cyclic_devices_are_errors() {
+        let graph = parse_input("svr: you\nyou: aaa\naaa: you out\n").unwrap();
+        let cycle = Cycle(vec!["aaa".to_string(), "you".to_string()]);
+        assert_eq!(part_one(&graph), Err(cycle.clone()));
+        assert_eq!(part_two(&graph), Err(cycle));
+    }
+}
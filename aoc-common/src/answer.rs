@@ -0,0 +1,95 @@
+use std::fmt;
+
+/// What a part of a puzzle evaluates to. Most answers are numbers; a few are
+/// text, such as a program's output or a list of names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+macro_rules! number_answer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+number_answer!(i32, i64, i128, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Why a part has no answer, such as an input that the solution can't
+/// handle. Reported to the user instead of aborting the run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartError(pub String);
+
+impl fmt::Display for PartError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for PartError {}
+
+/// What a part may return: an answer, or a `Result` whose error explains why
+/// there isn't one.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Answer, PartError>;
+}
+
+impl<T: Into<Answer>> IntoAnswer for T {
+    fn into_answer(self) -> Result<Answer, PartError> {
+        Ok(self.into())
+    }
+}
+
+impl<T: Into<Answer>, E: fmt::Display> IntoAnswer for Result<T, E> {
+    fn into_answer(self) -> Result<Answer, PartError> {
+        self.map(Into::into)
+            .map_err(|err| PartError(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_display_like_their_values() {
+        assert_eq!(Answer::from(-42i64).to_string(), "-42");
+        assert_eq!(Answer::from(u64::MAX).to_string(), u64::MAX.to_string());
+        assert_eq!(Answer::from("4,6,3").to_string(), "4,6,3");
+    }
+
+    #[test]
+    fn results_carry_their_errors() {
+        assert_eq!(7u32.into_answer(), Ok(Answer::Number(7)));
+        let failed: Result<u32, &str> = Err("no quine");
+        assert_eq!(failed.into_answer(), Err(PartError("no quine".to_string())));
+    }
+}
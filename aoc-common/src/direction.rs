@@ -0,0 +1,292 @@
+use crate::Point;
+use std::ops::Add;
+
+/// One of the four directions along the axes of a [`Grid`](crate::Grid).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise, starting from [`Direction::Up`].
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Reads one of `^>v<`.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        Self::from_notation(c, "^>v<")
+    }
+
+    /// Reads one of `UDLR`, for up, down, left and right.
+    pub fn from_letter(c: char) -> Option<Self> {
+        Self::from_notation(c, "URDL")
+    }
+
+    /// Reads one of `NESW`, with north pointing up.
+    pub fn from_compass_point(c: char) -> Option<Self> {
+        Self::from_notation(c, "NESW")
+    }
+
+    /// `notation` spells out [`Direction::ALL`], one character each.
+    fn from_notation(c: char, notation: &str) -> Option<Self> {
+        notation
+            .chars()
+            .position(|symbol| symbol == c)
+            .map(|index| Direction::ALL[index])
+    }
+
+    /// The offset of a single step in this direction.
+    pub const fn delta(self) -> Point {
+        match self {
+            Direction::Up => Point::new(-1, 0),
+            Direction::Right => Point::new(0, 1),
+            Direction::Down => Point::new(1, 0),
+            Direction::Left => Point::new(0, -1),
+        }
+    }
+
+    /// A quarter turn clockwise.
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    /// A quarter turn counterclockwise.
+    pub const fn turn_left(self) -> Self {
+        self.opposite().turn_right()
+    }
+
+    pub const fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, direction: Direction) -> Point {
+        self + direction.delta()
+    }
+}
+
+/// One of the eight directions of a compass, diagonals included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Compass {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Compass {
+    /// Clockwise, starting from [`Compass::North`].
+    pub const ALL: [Compass; 8] = [
+        Compass::North,
+        Compass::NorthEast,
+        Compass::East,
+        Compass::SouthEast,
+        Compass::South,
+        Compass::SouthWest,
+        Compass::West,
+        Compass::NorthWest,
+    ];
+
+    /// The offset of a single step in this direction, with north pointing up.
+    pub const fn delta(self) -> Point {
+        match self {
+            Compass::North => Point::new(-1, 0),
+            Compass::NorthEast => Point::new(-1, 1),
+            Compass::East => Point::new(0, 1),
+            Compass::SouthEast => Point::new(1, 1),
+            Compass::South => Point::new(1, 0),
+            Compass::SouthWest => Point::new(1, -1),
+            Compass::West => Point::new(0, -1),
+            Compass::NorthWest => Point::new(-1, -1),
+        }
+    }
+
+    /// An eighth of a turn clockwise.
+    pub const fn turn_right(self) -> Self {
+        Compass::ALL[(self as usize + 1) % 8]
+    }
+
+    /// An eighth of a turn counterclockwise.
+    pub const fn turn_left(self) -> Self {
+        Compass::ALL[(self as usize + 7) % 8]
+    }
+
+    pub const fn opposite(self) -> Self {
+        Compass::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction> for Compass {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Compass::North,
+            Direction::Right => Compass::East,
+            Direction::Down => Compass::South,
+            Direction::Left => Compass::West,
+        }
+    }
+}
+
+impl Add<Compass> for Point {
+    type Output = Point;
+
+    fn add(self, direction: Compass) -> Point {
+        self + direction.delta()
+    }
+}
+
+/// One of the six directions of a grid of flat-topped hexagons.
+///
+/// Cells are addressed in axial coordinates: `row` grows to the south and
+/// `col` to the south-east, so that [`HexDirection::distance`] is cheap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum HexDirection {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+impl HexDirection {
+    /// Clockwise, starting from [`HexDirection::North`].
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::North,
+        HexDirection::NorthEast,
+        HexDirection::SouthEast,
+        HexDirection::South,
+        HexDirection::SouthWest,
+        HexDirection::NorthWest,
+    ];
+
+    /// Reads `n`, `ne`, `se`, `s`, `sw` or `nw`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "n" => Some(HexDirection::North),
+            "ne" => Some(HexDirection::NorthEast),
+            "se" => Some(HexDirection::SouthEast),
+            "s" => Some(HexDirection::South),
+            "sw" => Some(HexDirection::SouthWest),
+            "nw" => Some(HexDirection::NorthWest),
+            _ => None,
+        }
+    }
+
+    /// The offset of a single step in this direction, in axial coordinates.
+    pub const fn delta(self) -> Point {
+        match self {
+            HexDirection::North => Point::new(-1, 0),
+            HexDirection::NorthEast => Point::new(-1, 1),
+            HexDirection::SouthEast => Point::new(0, 1),
+            HexDirection::South => Point::new(1, 0),
+            HexDirection::SouthWest => Point::new(1, -1),
+            HexDirection::NorthWest => Point::new(0, -1),
+        }
+    }
+
+    /// A sixth of a turn clockwise.
+    pub const fn turn_right(self) -> Self {
+        HexDirection::ALL[(self as usize + 1) % 6]
+    }
+
+    /// A sixth of a turn counterclockwise.
+    pub const fn turn_left(self) -> Self {
+        HexDirection::ALL[(self as usize + 5) % 6]
+    }
+
+    pub const fn opposite(self) -> Self {
+        HexDirection::ALL[(self as usize + 3) % 6]
+    }
+
+    /// The fewest steps between two cells in axial coordinates.
+    pub fn distance(from: Point, to: Point) -> i32 {
+        let offset = to - from;
+        (offset.row.abs() + offset.col.abs() + (offset.row + offset.col).abs()) / 2
+    }
+}
+
+impl Add<HexDirection> for Point {
+    type Output = Point;
+
+    fn add(self, direction: HexDirection) -> Point {
+        self + direction.delta()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_compose() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.opposite().delta(), direction.delta() * -1);
+            assert_eq!(
+                Compass::from(direction).turn_right().turn_right(),
+                direction.turn_right().into()
+            );
+        }
+        for direction in Compass::ALL {
+            assert_eq!(direction.opposite().delta(), direction.delta() * -1);
+        }
+        for direction in HexDirection::ALL {
+            assert_eq!(direction.opposite().delta(), direction.delta() * -1);
+            assert_eq!(direction.turn_right().turn_left(), direction);
+        }
+    }
+
+    #[test]
+    fn directions_are_parsed() {
+        let arrows: Vec<_> = "^>v<".chars().filter_map(Direction::from_arrow).collect();
+        assert_eq!(arrows, Direction::ALL);
+        assert_eq!(Direction::from_letter('L'), Some(Direction::Left));
+        assert_eq!(Direction::from_compass_point('S'), Some(Direction::Down));
+        assert_eq!(Direction::from_arrow('U'), None);
+    }
+
+    #[test]
+    fn hex_steps_are_counted() {
+        let walk = |path: &str| {
+            path.split(',')
+                .map(|name| HexDirection::from_name(name).unwrap())
+                .fold(Point::default(), |point, direction| point + direction)
+        };
+        assert_eq!(
+            HexDirection::distance(Point::default(), walk("ne,ne,ne")),
+            3
+        );
+        assert_eq!(
+            HexDirection::distance(Point::default(), walk("ne,ne,sw,sw")),
+            0
+        );
+        assert_eq!(
+            HexDirection::distance(Point::default(), walk("ne,ne,s,s")),
+            2
+        );
+        assert_eq!(
+            HexDirection::distance(Point::default(), walk("se,sw,se,sw,sw")),
+            3
+        );
+    }
+}
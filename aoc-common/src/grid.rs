@@ -0,0 +1,358 @@
+use crate::{Direction, ParseError, Span, non_empty_lines};
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Sub},
+};
+
+/// A cell of a [`Grid`], or the offset between two cells. Rows grow downwards.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: i32,
+    pub col: i32,
+}
+
+impl Point {
+    pub const fn new(row: i32, col: i32) -> Self {
+        Point { row, col }
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> i32 {
+        (self.row - other.row).abs() + (self.col - other.col).abs()
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i32) -> Point {
+        Point::new(self.row * factor, self.col * factor)
+    }
+}
+
+/// Offsets to the eight cells around a cell, in reading order.
+const SURROUNDING: [Point; 8] = [
+    Point::new(-1, -1),
+    Point::new(-1, 0),
+    Point::new(-1, 1),
+    Point::new(0, -1),
+    Point::new(0, 1),
+    Point::new(1, -1),
+    Point::new(1, 0),
+    Point::new(1, 1),
+];
+
+/// A rectangle of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `rows` by `cols` grid with every cell set to `value`.
+    pub fn new(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            rows,
+            cols,
+            cells: vec![value; rows * cols],
+        }
+    }
+
+    /// Panics unless every row is as long as the first.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "rows of a grid must have the same length"
+        );
+        Grid {
+            rows: rows.len(),
+            cols,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// One row per line, turning every cell into a `T` with `parse_cell`.
+    /// Every row must be as wide as the first.
+    pub fn parse_lines_with<'a>(
+        lines: impl IntoIterator<Item = Span<'a>>,
+        mut parse_cell: impl FnMut(char, Span<'a>) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut grid = Grid {
+            rows: 0,
+            cols: 0,
+            cells: Vec::new(),
+        };
+        for line in lines {
+            let start = grid.cells.len();
+            for (c, span) in line.chars() {
+                grid.cells.push(parse_cell(c, span)?);
+            }
+            let width = grid.cells.len() - start;
+            if grid.rows == 0 {
+                grid.cols = width;
+            } else if width != grid.cols {
+                return Err(match line.text().char_indices().nth(grid.cols) {
+                    Some((idx, _)) => line.slice(&line.text()[idx..]).error("end of line"),
+                    None => line.end().error(format!("{} columns", grid.cols)),
+                });
+            }
+            grid.rows += 1;
+        }
+        Ok(grid)
+    }
+
+    /// Like [`Grid::parse_lines_with`] over the non-empty lines of `input`,
+    /// which must hold at least one.
+    pub fn parse_with<'a>(
+        input: &'a str,
+        parse_cell: impl FnMut(char, Span<'a>) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let grid = Self::parse_lines_with(non_empty_lines(input), parse_cell)?;
+        if grid.is_empty() {
+            return Err(ParseError::end_of_input(input, "a grid"));
+        }
+        Ok(grid)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (0..self.rows as i32).contains(&point.row) && (0..self.cols as i32).contains(&point.col)
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.row as usize * self.cols + point.col as usize)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|offset| &mut self.cells[offset])
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Every cell, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> + use<T> {
+        let cols = self.cols as i32;
+        (0..self.rows as i32).flat_map(move |row| (0..cols).map(move |col| Point::new(row, col)))
+    }
+
+    /// Every cell with its value, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// The first cell, in reading order, whose value satisfies `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(point, _)| point)
+    }
+
+    /// The first cell, in reading order, holding `value`.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    /// The up to four cells next to `point` that are inside the grid.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| point + direction)
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    /// Like [`Grid::neighbours`], but including diagonals.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        SURROUNDING
+            .into_iter()
+            .map(move |offset| point + offset)
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.cols)
+            .flat_map(|col| (0..self.rows).map(move |row| (row, col)))
+            .map(|(row, col)| self.cells[row * self.cols + col].clone())
+            .collect();
+        Grid {
+            rows: self.cols,
+            cols: self.rows,
+            cells,
+        }
+    }
+
+    /// Turns the grid a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut rotated = self.transpose();
+        for row in rotated.cells.chunks_mut(rotated.cols.max(1)) {
+            row.reverse();
+        }
+        rotated
+    }
+
+    /// Turns the grid a quarter turn counterclockwise.
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut rotated = self.transpose();
+        let cols = rotated.cols;
+        let rows: Vec<Vec<T>> = rotated
+            .cells
+            .chunks(cols.max(1))
+            .rev()
+            .map(<[T]>::to_vec)
+            .collect();
+        rotated.cells = rows.into_iter().flatten().collect();
+        rotated
+    }
+}
+
+impl Grid<char> {
+    /// One cell per character of every non-empty line of `input`.
+    pub fn parse_from_str(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, |c, _| Ok(c))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of a {}x{} grid", self.rows, self.cols))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of a {rows}x{cols} grid"))
+    }
+}
+
+/// Draws the grid one line per row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.rows {
+            for cell in self.row(row) {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lines;
+
+    #[test]
+    fn ragged_rows_are_reported() {
+        let err = Grid::parse_from_str("abc\nabcde\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+        assert_eq!(err.found, "`de`");
+
+        let err = Grid::parse_from_str("abc\nab\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.expected, "3 columns");
+    }
+
+    #[test]
+    fn empty_input_is_not_a_grid() {
+        assert!(Grid::parse_from_str("\n\n").is_err());
+        assert!(
+            Grid::parse_lines_with(lines(""), |c, _| Ok(c))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = Grid::parse_from_str("abc\ndef\n").unwrap();
+        assert_eq!(grid.find(&'e'), Some(Point::new(1, 1)));
+        let corner: Vec<char> = grid.neighbours(Point::new(0, 0)).map(|p| grid[p]).collect();
+        assert_eq!(corner, ['b', 'd']);
+        let middle: Vec<char> = grid
+            .neighbours8(Point::new(1, 1))
+            .map(|p| grid[p])
+            .collect();
+        assert_eq!(middle, ['a', 'b', 'c', 'd', 'f']);
+    }
+
+    #[test]
+    fn transposes_and_rotates() {
+        let grid = Grid::parse_from_str("abc\ndef\n").unwrap();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        let turned = (0..4).fold(grid.clone(), |grid, _| grid.rotate_clockwise());
+        assert_eq!(turned, grid);
+    }
+}
@@ -0,0 +1,206 @@
+use std::{fmt, ops::RangeInclusive};
+
+/// Integers that can bound the spans of an [`IntervalSet`].
+pub trait Endpoint: Copy + Ord {
+    const MIN: Self;
+    const MAX: Self;
+
+    fn successor(self) -> Option<Self>;
+
+    fn predecessor(self) -> Option<Self>;
+
+    /// How many values `start..=end` holds, which mustn't be empty. A span
+    /// of every `u64` holds one more value than a `u64` can count.
+    fn span_len(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_endpoint {
+    ($($t:ty),*) => {$(
+        impl Endpoint for $t {
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+
+            fn successor(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn predecessor(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn span_len(start: Self, end: Self) -> u128 {
+                (end as i128 - start as i128 + 1) as u128
+            }
+        }
+    )*};
+}
+
+impl_endpoint!(i32, i64, u32, u64, usize);
+
+/// A set of integers stored as sorted, disjoint spans. Spans that touch are
+/// merged, so every set has exactly one representation.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    spans: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { spans: Vec::new() }
+    }
+}
+
+impl<T: Endpoint> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every value of `range`, merging it with the spans it overlaps or
+    /// touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // The spans from `first` to `last` overlap or touch the new one.
+        let first = self.spans.partition_point(|&(_, span_end)| {
+            span_end.successor().is_some_and(|after| after < start)
+        });
+        let last = self.spans.partition_point(|&(span_start, _)| {
+            span_start.predecessor().is_none_or(|before| before <= end)
+        });
+        if first < last {
+            start = start.min(self.spans[first].0);
+            end = end.max(self.spans[last - 1].1);
+        }
+        self.spans.splice(first..last, [(start, end)]);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.spans.partition_point(|&(_, end)| end < value);
+        self.spans
+            .get(idx)
+            .is_some_and(|&(start, _)| start <= value)
+    }
+
+    /// How many values the set holds. The spans are disjoint, so this is at
+    /// most one more than `T::MAX - T::MIN` and the sum can't overflow.
+    pub fn len(&self) -> u128 {
+        self.spans
+            .iter()
+            .map(|&(start, end)| T::span_len(start, end))
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// The disjoint spans of the set, in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.spans.iter().map(|&(start, end)| start..=end)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.iter().chain(other.iter()).collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut spans = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&(a_start, a_end)), Some(&(b_start, b_end))) =
+            (self.spans.get(i), other.spans.get(j))
+        {
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                spans.push((start, end));
+            }
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { spans }
+    }
+
+    /// The values of `self` that aren't in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement(T::MIN..=T::MAX))
+    }
+
+    /// The values of `bounds` that aren't in the set.
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+        let (low, high) = bounds.into_inner();
+        let mut spans = Vec::new();
+        let mut next = Some(low);
+        for &(start, end) in &self.spans {
+            let Some(from) = next else {
+                break;
+            };
+            if let Some(before) = start.predecessor()
+                && from <= before
+            {
+                spans.push((from, before.min(high)));
+            }
+            next = end.successor().map(|after| after.max(from));
+        }
+        if let Some(from) = next {
+            spans.push((from, high));
+        }
+        spans.retain(|&(start, end)| start <= end && start <= high);
+        IntervalSet { spans }
+    }
+}
+
+impl<T: Endpoint> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = IntervalSet::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for IntervalSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(self.spans.iter().map(|(start, end)| start..=end))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inserts_merge_touching_spans() {
+        let set: IntervalSet<u64> = [3..=5, 10..=14, 16..=20, 12..=18, 6..=6]
+            .into_iter()
+            .collect();
+        assert_eq!(set.iter().collect::<Vec<_>>(), [3..=6, 10..=20]);
+        assert_eq!(set.len(), 15);
+        assert!(set.contains(6) && set.contains(17));
+        assert!(!set.contains(7) && !set.contains(21) && !set.contains(2));
+    }
+
+    #[test]
+    fn algebra() {
+        let a: IntervalSet<i32> = [0..=10, 20..=30].into_iter().collect();
+        let b: IntervalSet<i32> = [5..=25].into_iter().collect();
+        let spans = |set: IntervalSet<i32>| set.iter().collect::<Vec<_>>();
+        assert_eq!(spans(a.union(&b)), [0..=30]);
+        assert_eq!(spans(a.intersection(&b)), [5..=10, 20..=25]);
+        assert_eq!(spans(a.difference(&b)), [0..=4, 26..=30]);
+        assert_eq!(spans(a.complement(-5..=35)), [-5..=-1, 11..=19, 31..=35]);
+        assert_eq!(spans(a.complement(12..=18)), [12..=18]);
+        assert_eq!(spans(a.complement(2..=8)), []);
+        let full: IntervalSet<u64> = [0..=u64::MAX].into_iter().collect();
+        assert!(full.complement(0..=u64::MAX).is_empty());
+        assert_eq!(full.len(), 1 << 64);
+        let signed: IntervalSet<i64> = [i64::MIN..=-1, 0..=i64::MAX].into_iter().collect();
+        assert_eq!(signed.len(), 1 << 64);
+    }
+}
@@ -0,0 +1,99 @@
+mod answer;
+mod direction;
+mod disjoint_set;
+mod grid;
+mod interval_set;
+mod parse;
+pub mod search;
+pub mod toposort;
+
+pub use answer::{Answer, IntoAnswer, PartError};
+pub use direction::{Compass, Direction, HexDirection};
+pub use disjoint_set::DisjointSet;
+pub use grid::{Grid, Point};
+pub use interval_set::{Endpoint, IntervalSet};
+pub use parse::{ParseError, Span, lines, sections, split_sections};
+
+use std::{
+    error::Error,
+    fmt,
+    io::{self, BufRead},
+    process,
+};
+
+/// Either the input could not be read, or it was malformed.
+#[derive(Debug)]
+pub enum InputError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Io(err) => write!(f, "cannot read input: {err}"),
+            InputError::Parse(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+impl From<io::Error> for InputError {
+    fn from(err: io::Error) -> Self {
+        InputError::Io(err)
+    }
+}
+
+impl From<ParseError> for InputError {
+    fn from(err: ParseError) -> Self {
+        InputError::Parse(err)
+    }
+}
+
+/// Reads all of `reader` and parses it with `parse`.
+pub fn parse_reader<T>(
+    mut reader: impl BufRead,
+    parse: impl FnOnce(&str) -> Result<T, ParseError>,
+) -> Result<T, InputError> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    Ok(parse(&input)?)
+}
+
+/// Parses stdin with `parse`, exiting with a diagnostic if the input is malformed.
+pub fn parse_stdin<T>(parse: impl FnOnce(&str) -> Result<T, ParseError>) -> T {
+    match parse_reader(io::stdin().lock(), parse) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {err}");
+            process::exit(1);
+        }
+    }
+}
+
+/// The value in `result`, exiting with a diagnostic if a part failed.
+pub fn exit_on_error<T, E: fmt::Display>(result: Result<T, E>) -> T {
+    result.unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        process::exit(1);
+    })
+}
+
+/// The lines of `input`, skipping blank ones.
+pub fn non_empty_lines(input: &str) -> impl Iterator<Item = Span<'_>> {
+    lines(input).filter(|line| !line.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn readers_are_parsed_like_strings() {
+        let grid = parse_reader("ab\ncd\n".as_bytes(), Grid::parse_from_str).unwrap();
+        assert_eq!(grid, Grid::from_rows(vec![vec!['a', 'b'], vec!['c', 'd']]));
+        let err = parse_reader("ab\nc\n".as_bytes(), Grid::parse_from_str).unwrap_err();
+        assert!(matches!(err, InputError::Parse(ParseError { line: 2, .. })));
+    }
+}
@@ -0,0 +1,252 @@
+use std::{any::type_name, error::Error, fmt, iter, str::FromStr};
+
+/// Malformed puzzle input. Lines and columns are numbered from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    /// The input stopped before something we still needed.
+    pub fn end_of_input(input: &str, expected: impl Into<String>) -> Self {
+        ParseError {
+            line: input.lines().count() + 1,
+            column: 1,
+            expected: expected.into(),
+            found: String::from("end of input"),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A piece of one input line that remembers where it came from, so that
+/// errors can point at it.
+#[derive(Debug, Clone, Copy)]
+pub struct Span<'a> {
+    line: usize,
+    column: usize,
+    text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// The span of `part`, which must be a slice of this span's text.
+    pub fn slice(&self, part: &'a str) -> Span<'a> {
+        let offset = part.as_ptr() as usize - self.text.as_ptr() as usize;
+        assert!(offset + part.len() <= self.text.len());
+        Span {
+            line: self.line,
+            column: self.column + offset,
+            text: part,
+        }
+    }
+
+    /// The empty span just past the end of this one.
+    pub fn end(&self) -> Span<'a> {
+        self.slice(&self.text[self.text.len()..])
+    }
+
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            expected: expected.into(),
+            found: match self.text {
+                "" => String::from("end of line"),
+                text => format!("`{text}`"),
+            },
+        }
+    }
+
+    /// An error for a line that should have followed this one, because the
+    /// section ended too early.
+    pub fn missing_after(&self, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            line: self.line + 1,
+            column: 1,
+            expected: expected.into(),
+            found: String::from("end of section"),
+        }
+    }
+
+    pub fn parse<T: FromStr>(&self) -> Result<T, ParseError> {
+        self.text
+            .parse()
+            .map_err(|_| self.error(format!("a valid `{}`", type_name::<T>())))
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Span<'a>, ParseError> {
+        match self.text.strip_prefix(prefix) {
+            Some(rest) => Ok(self.slice(rest)),
+            None => Err(self.error(format!("`{prefix}`"))),
+        }
+    }
+
+    pub fn split_once(&self, delimiter: &str) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        match self.text.split_once(delimiter) {
+            Some((left, right)) => Ok((self.slice(left), self.slice(right))),
+            None => Err(self.end().error(format!("`{delimiter}`"))),
+        }
+    }
+
+    pub fn split(self, delimiter: &'a str) -> impl Iterator<Item = Span<'a>> {
+        self.text.split(delimiter).map(move |part| self.slice(part))
+    }
+
+    pub fn split_whitespace(self) -> impl Iterator<Item = Span<'a>> {
+        self.text
+            .split_whitespace()
+            .map(move |part| self.slice(part))
+    }
+
+    /// Every character along with its own one-character span.
+    pub fn chars(self) -> impl Iterator<Item = (char, Span<'a>)> {
+        self.text
+            .char_indices()
+            .map(move |(idx, c)| (c, self.slice(&self.text[idx..idx + c.len_utf8()])))
+    }
+
+    /// Splits on `delimiter` into exactly `N` pieces.
+    pub fn split_exact<const N: usize>(
+        self,
+        delimiter: &'a str,
+    ) -> Result<[Span<'a>; N], ParseError> {
+        self.exact(self.split(delimiter), &format!("`{delimiter}`"))
+    }
+
+    /// Splits on whitespace into exactly `N` pieces.
+    pub fn split_whitespace_exact<const N: usize>(self) -> Result<[Span<'a>; N], ParseError> {
+        self.exact(self.split_whitespace(), "another value")
+    }
+
+    fn exact<const N: usize>(
+        &self,
+        mut pieces: impl Iterator<Item = Span<'a>>,
+        expected: &str,
+    ) -> Result<[Span<'a>; N], ParseError> {
+        let mut result = [self.end(); N];
+        for piece in result.iter_mut() {
+            *piece = pieces.next().ok_or_else(|| self.end().error(expected))?;
+        }
+        match pieces.next() {
+            Some(extra) => {
+                let offset = extra.column - self.column;
+                Err(self.slice(&self.text[offset..]).error("end of line"))
+            }
+            None => Ok(result),
+        }
+    }
+}
+
+/// The numbered lines of `input`. Trailing whitespace, including a stray
+/// carriage return, is not part of a line.
+pub fn lines(input: &str) -> impl Iterator<Item = Span<'_>> {
+    input.lines().enumerate().map(|(idx, text)| Span {
+        line: idx + 1,
+        column: 1,
+        text: text.trim_end(),
+    })
+}
+
+/// The blank-line separated sections of `input`, each as a list of lines.
+/// Several blank lines in a row separate just like one.
+pub fn sections(input: &str) -> impl Iterator<Item = Vec<Span<'_>>> {
+    let mut lines = lines(input).peekable();
+    iter::from_fn(move || {
+        while lines.next_if(|line| line.is_empty()).is_some() {}
+        let section: Vec<_> = lines.by_ref().take_while(|line| !line.is_empty()).collect();
+        (!section.is_empty()).then_some(section)
+    })
+}
+
+/// Exactly `N` sections, for inputs with a fixed layout.
+pub fn split_sections<const N: usize>(input: &str) -> Result<[Vec<Span<'_>>; N], ParseError> {
+    let mut sections = sections(input);
+    let mut result: [Vec<Span>; N] = std::array::from_fn(|_| Vec::new());
+    for section in result.iter_mut() {
+        *section = sections
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(input, "another section"))?;
+    }
+    match sections.next() {
+        Some(extra) => Err(extra[0].error("end of input")),
+        None => Ok(result),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn errors_point_at_the_offending_token() {
+        let line = lines("7 6\n1 x 3\n").nth(1).unwrap();
+        let err = line
+            .split_whitespace()
+            .map(|token| token.parse::<i32>())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected a valid `i32`, found `x`"
+        );
+    }
+
+    #[test]
+    fn split_exact_rejects_missing_and_extra_pieces() {
+        let line = lines("1,2").next().unwrap();
+        let err = line.split_exact::<3>(",").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (4, "end of line"));
+        let err = line.split_exact::<1>(",").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (3, "`2`"));
+    }
+
+    #[test]
+    fn sections_are_split_on_blank_lines() {
+        let sections: Vec<Vec<_>> = sections("\na\nb\n\n\n \nc\n\n")
+            .map(|section| {
+                section
+                    .iter()
+                    .map(|line| (line.line(), line.text()))
+                    .collect()
+            })
+            .collect();
+        assert_eq!(sections, [vec![(2, "a"), (3, "b")], vec![(7, "c")]]);
+    }
+
+    #[test]
+    fn lines_ignore_trailing_whitespace() {
+        let texts: Vec<_> = lines("a \r\nb\r\n").map(|line| line.text()).collect();
+        assert_eq!(texts, ["a", "b"]);
+    }
+}
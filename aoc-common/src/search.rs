@@ -0,0 +1,362 @@
+//! Shortest paths over graphs that are only known through a successor
+//! function, such as a grid position paired with a heading.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// What a search learned about every state it settled: how far each one is
+/// from the nearest start, and which states precede it on shortest paths.
+pub struct Search<S> {
+    states: Vec<S>,
+    index: HashMap<S, usize>,
+    distances: Vec<u64>,
+    settled: Vec<bool>,
+    predecessors: Vec<Vec<usize>>,
+    goal: Option<usize>,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    fn new(starts: impl IntoIterator<Item = S>) -> Self {
+        let mut search = Search {
+            states: Vec::new(),
+            index: HashMap::new(),
+            distances: Vec::new(),
+            settled: Vec::new(),
+            predecessors: Vec::new(),
+            goal: None,
+        };
+        for start in starts {
+            search.relax(None, start, 0);
+        }
+        search
+    }
+
+    /// Records that `state` can be reached at `distance`, through `from`.
+    /// Returns the state's index if that is the best distance seen so far.
+    fn relax(&mut self, from: Option<usize>, state: S, distance: u64) -> Option<usize> {
+        let idx = match self.index.get(&state) {
+            Some(&idx) => idx,
+            None => {
+                let idx = self.states.len();
+                self.index.insert(state.clone(), idx);
+                self.states.push(state);
+                self.distances.push(u64::MAX);
+                self.settled.push(false);
+                self.predecessors.push(Vec::new());
+                idx
+            }
+        };
+        if distance < self.distances[idx] {
+            self.distances[idx] = distance;
+            self.predecessors[idx] = from.into_iter().collect();
+            Some(idx)
+        } else {
+            // Starts have no predecessors, even if a zero-cost cycle leads
+            // back to them.
+            if distance == self.distances[idx]
+                && let Some(from) = from
+                && !self.predecessors[idx].is_empty()
+                && !self.predecessors[idx].contains(&from)
+            {
+                self.predecessors[idx].push(from);
+            }
+            None
+        }
+    }
+
+    /// The length of the shortest path from any start to `state`, if the
+    /// search settled it.
+    pub fn distance(&self, state: &S) -> Option<u64> {
+        let &idx = self.index.get(state)?;
+        self.settled[idx].then_some(self.distances[idx])
+    }
+
+    /// Every settled state with its distance, nearest first.
+    pub fn distances(&self) -> impl Iterator<Item = (&S, u64)> {
+        let mut settled: Vec<usize> = (0..self.states.len())
+            .filter(|&idx| self.settled[idx])
+            .collect();
+        settled.sort_by_key(|&idx| self.distances[idx]);
+        settled
+            .into_iter()
+            .map(|idx| (&self.states[idx], self.distances[idx]))
+    }
+
+    /// The states right before `state` on its shortest paths. Empty for the
+    /// starts and for states that weren't reached.
+    pub fn predecessors(&self, state: &S) -> impl Iterator<Item = &S> {
+        let predecessors = match self.index.get(state) {
+            Some(&idx) => &self.predecessors[idx][..],
+            None => &[],
+        };
+        predecessors.iter().map(|&idx| &self.states[idx])
+    }
+
+    /// The goal that [`astar`] stopped at.
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.map(|idx| &self.states[idx])
+    }
+
+    /// One shortest path from a start to `state`, both included.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        self.distance(state)?;
+        let mut idx = self.index[state];
+        let mut path = vec![self.states[idx].clone()];
+        while let Some(&previous) = self.predecessors[idx].first() {
+            idx = previous;
+            path.push(self.states[idx].clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every shortest path from a start to `state`. There can be
+    /// exponentially many; [`Search::path_count`] only counts them.
+    pub fn paths_to(&self, state: &S) -> Vec<Vec<S>> {
+        if self.distance(state).is_none() {
+            return Vec::new();
+        }
+        self.paths_through(self.index[state], &mut vec![false; self.states.len()])
+    }
+
+    /// The paths to `idx` that avoid the states in `on_path`, which zero-cost
+    /// cycles could otherwise lead back to.
+    fn paths_through(&self, idx: usize, on_path: &mut [bool]) -> Vec<Vec<S>> {
+        if self.predecessors[idx].is_empty() {
+            return vec![vec![self.states[idx].clone()]];
+        }
+        on_path[idx] = true;
+        let mut paths = Vec::new();
+        for &previous in &self.predecessors[idx] {
+            if on_path[previous] {
+                continue;
+            }
+            for mut path in self.paths_through(previous, on_path) {
+                path.push(self.states[idx].clone());
+                paths.push(path);
+            }
+        }
+        on_path[idx] = false;
+        paths
+    }
+
+    /// How many shortest paths lead from a start to `state`. Paths around a
+    /// cycle of zero-cost edges are only counted once.
+    pub fn path_count(&self, state: &S) -> u64 {
+        let Some(&target) = self.index.get(state) else {
+            return 0;
+        };
+        if !self.settled[target] {
+            return 0;
+        }
+        // Zero-cost edges let a predecessor have the same distance as the
+        // state it precedes, so count in depth-first post-order instead of
+        // by distance.
+        let mut counts: Vec<Option<u64>> = vec![None; self.states.len()];
+        let mut on_stack = vec![false; self.states.len()];
+        on_stack[target] = true;
+        let mut stack = vec![(target, 0)];
+        while let Some((idx, edge)) = stack.last_mut() {
+            let idx = *idx;
+            if let Some(&previous) = self.predecessors[idx].get(*edge) {
+                *edge += 1;
+                if counts[previous].is_none() && !on_stack[previous] {
+                    on_stack[previous] = true;
+                    stack.push((previous, 0));
+                }
+                continue;
+            }
+            let count = if self.predecessors[idx].is_empty() {
+                1
+            } else {
+                self.predecessors[idx]
+                    .iter()
+                    .filter_map(|&previous| counts[previous])
+                    .sum()
+            };
+            counts[idx] = Some(count);
+            on_stack[idx] = false;
+            stack.pop();
+        }
+        counts[target].unwrap()
+    }
+
+    /// Every state on a shortest path from a start to the nearest of
+    /// `targets`, the targets themselves included.
+    pub fn on_shortest_paths(&self, targets: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let reached: Vec<usize> = targets
+            .into_iter()
+            .filter_map(|target| self.distance(&target).map(|_| self.index[&target]))
+            .collect();
+        let Some(best) = reached.iter().map(|&idx| self.distances[idx]).min() else {
+            return HashSet::new();
+        };
+        let mut stack: Vec<usize> = reached
+            .into_iter()
+            .filter(|&idx| self.distances[idx] == best)
+            .collect();
+        let mut seen: HashSet<usize> = stack.iter().copied().collect();
+        while let Some(idx) = stack.pop() {
+            for &previous in &self.predecessors[idx] {
+                if seen.insert(previous) {
+                    stack.push(previous);
+                }
+            }
+        }
+        seen.into_iter()
+            .map(|idx| self.states[idx].clone())
+            .collect()
+    }
+}
+
+/// Breadth-first search from every one of `starts`, where each step costs one.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new(starts);
+    let mut queue: VecDeque<usize> = (0..search.states.len()).collect();
+    while let Some(idx) = queue.pop_front() {
+        if search.settled[idx] {
+            continue;
+        }
+        search.settled[idx] = true;
+        let distance = search.distances[idx] + 1;
+        let state = search.states[idx].clone();
+        for next in successors(&state) {
+            if let Some(next) = search.relax(Some(idx), next, distance) {
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's algorithm from every one of `starts`, where `successors`
+/// yields each neighbouring state with the cost of moving there.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(starts, successors, |_| 0, |_| false)
+}
+
+/// Like [`dijkstra`], but guided towards a goal by `heuristic`. Settled states
+/// are never reopened, so the heuristic must be consistent: zero at goals,
+/// and never dropping by more than the cost of a step. Stops once every
+/// shortest path to the nearest goal is known; [`Search::goal`] tells which
+/// goal that was.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut search = Search::new(starts);
+    let mut heap: BinaryHeap<Reverse<(u64, u64, usize)>> = (0..search.states.len())
+        .map(|idx| Reverse((heuristic(&search.states[idx]), 0, idx)))
+        .collect();
+    let mut goal_distance = None;
+    while let Some(Reverse((estimate, distance, idx))) = heap.pop() {
+        if goal_distance.is_some_and(|goal_distance| estimate > goal_distance) {
+            break;
+        }
+        if search.settled[idx] || distance > search.distances[idx] {
+            continue;
+        }
+        search.settled[idx] = true;
+        let state = search.states[idx].clone();
+        if goal_distance.is_none() && is_goal(&state) {
+            goal_distance = Some(distance);
+            search.goal = Some(idx);
+        }
+        for (next, cost) in successors(&state) {
+            let next_distance = distance + cost;
+            let estimate = next_distance + heuristic(&next);
+            if let Some(next) = search.relax(Some(idx), next, next_distance) {
+                heap.push(Reverse((estimate, next_distance, next)));
+            }
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A diamond `0 -> {1, 2} -> 3`, then a costly edge `3 -> 4` and a
+    /// cheap detour `3 -> 5 -> 4`.
+    fn successors(&state: &u32) -> Vec<(u32, u64)> {
+        match state {
+            0 => vec![(1, 1), (2, 1)],
+            1 | 2 => vec![(3, 1)],
+            3 => vec![(4, 5), (5, 1)],
+            5 => vec![(4, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn dijkstra_keeps_every_shortest_path() {
+        let search = dijkstra([0], successors);
+        assert_eq!(search.distance(&4), Some(4));
+        assert_eq!(search.path_to(&4), Some(vec![0, 1, 3, 5, 4]));
+        assert_eq!(search.paths_to(&4), [[0, 1, 3, 5, 4], [0, 2, 3, 5, 4]]);
+        assert_eq!(search.path_count(&4), 2);
+        assert_eq!(
+            search.on_shortest_paths([4, 5]),
+            HashSet::from([0, 1, 2, 3, 5])
+        );
+        assert_eq!(search.distance(&6), None);
+    }
+
+    #[test]
+    fn zero_cost_edges_keep_their_paths() {
+        let search = dijkstra([0], |&state: &u32| match state {
+            0 => vec![(1, 0), (2, 1)],
+            1 => vec![(2, 1), (0, 0)],
+            _ => vec![],
+        });
+        assert_eq!(search.distance(&2), Some(1));
+        assert_eq!(search.path_count(&2), 2);
+        assert_eq!(search.paths_to(&2), [vec![0, 2], vec![0, 1, 2]]);
+    }
+
+    #[test]
+    fn bfs_counts_steps_from_every_start() {
+        let unweighted = |state: &u32| successors(state).into_iter().map(|(next, _)| next);
+        let search = bfs([0, 3], unweighted);
+        assert_eq!(search.distance(&4), Some(1));
+        assert_eq!(search.distance(&2), Some(1));
+        assert_eq!(search.predecessors(&4).collect::<Vec<_>>(), [&3]);
+    }
+
+    #[test]
+    fn astar_stops_at_the_goal() {
+        let search = astar(
+            [0],
+            |&n: &i32| [(n - 1, 1), (n + 1, 1)],
+            |&n| (10 - n).unsigned_abs() as u64,
+            |&n| n == 10,
+        );
+        assert_eq!(search.goal(), Some(&10));
+        assert_eq!(search.distance(&10), Some(10));
+        assert_eq!(search.distance(&-5), None);
+    }
+}
@@ -0,0 +1,296 @@
+//! Orders the nodes of a directed graph so that every edge points forwards.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    error::Error,
+    fmt,
+    hash::Hash,
+};
+
+/// Which node goes first when several have no remaining dependencies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TieBreak {
+    /// Nodes are taken in the order they became free, and nodes that were
+    /// free from the start in the order they were added.
+    Insertion,
+    /// The smallest free node goes first. Costs a logarithmic factor.
+    Lexicographic,
+}
+
+/// Nodes that depend on each other in a loop, so that they can't be ordered.
+/// Each node has an edge to the next, and the last to the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<N>(pub Vec<N>);
+
+impl<N: fmt::Display> fmt::Display for Cycle<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cycle: ")?;
+        for node in &self.0 {
+            write!(f, "{node} -> ")?;
+        }
+        match self.0.first() {
+            Some(first) => write!(f, "{first}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<N: fmt::Debug + fmt::Display> Error for Cycle<N> {}
+
+/// A directed graph whose edges point from a node to the nodes that must
+/// come after it.
+#[derive(Debug, Clone)]
+pub struct Dag<N> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    successors: Vec<Vec<usize>>,
+}
+
+impl<N> Default for Dag<N> {
+    fn default() -> Self {
+        Dag {
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            successors: Vec::new(),
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash> Dag<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `node` unless it is already there, so that it is ordered even if
+    /// it has no edges.
+    pub fn add_node(&mut self, node: N) -> &mut Self {
+        self.node_index(node);
+        self
+    }
+
+    /// Makes `before` come before `after`.
+    pub fn add_edge(&mut self, before: N, after: N) -> &mut Self {
+        let before = self.node_index(before);
+        let after = self.node_index(after);
+        self.successors[before].push(after);
+        self
+    }
+
+    fn node_index(&mut self, node: N) -> usize {
+        if let Some(&idx) = self.index.get(&node) {
+            return idx;
+        }
+        let idx = self.nodes.len();
+        self.index.insert(node.clone(), idx);
+        self.nodes.push(node);
+        self.successors.push(Vec::new());
+        idx
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    fn order(&self, indices: Vec<usize>) -> Vec<N> {
+        indices
+            .into_iter()
+            .map(|idx| self.nodes[idx].clone())
+            .collect()
+    }
+
+    /// Starts the cycle from its earliest added node, so that both
+    /// algorithms report it the same way.
+    fn cycle(&self, mut indices: Vec<usize>) -> Cycle<N> {
+        let first = (0..indices.len()).min_by_key(|&i| indices[i]).unwrap_or(0);
+        indices.rotate_left(first);
+        Cycle(self.order(indices))
+    }
+
+    /// Kahn's algorithm: repeatedly takes a node that nothing left points to.
+    pub fn kahn(&self, tie_break: TieBreak) -> Result<Vec<N>, Cycle<N>>
+    where
+        N: Ord,
+    {
+        let mut indegree = vec![0; self.len()];
+        for &next in self.successors.iter().flatten() {
+            indegree[next] += 1;
+        }
+        let mut free = Free::new(self, tie_break);
+        for idx in (0..self.len()).filter(|&idx| indegree[idx] == 0) {
+            free.push(idx);
+        }
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(idx) = free.pop() {
+            order.push(idx);
+            for &next in &self.successors[idx] {
+                indegree[next] -= 1;
+                if indegree[next] == 0 {
+                    free.push(next);
+                }
+            }
+        }
+        if order.len() < self.len() {
+            return Err(self.find_cycle(&indegree));
+        }
+        Ok(self.order(order))
+    }
+
+    /// Every node that Kahn's algorithm couldn't take still has an edge
+    /// from another such node, so walking those edges backwards must loop.
+    fn find_cycle(&self, indegree: &[usize]) -> Cycle<N> {
+        let mut predecessor = vec![None; self.len()];
+        for (idx, successors) in self.successors.iter().enumerate() {
+            if indegree[idx] > 0 {
+                for &next in successors {
+                    predecessor[next].get_or_insert(idx);
+                }
+            }
+        }
+        let mut step = vec![None; self.len()];
+        let mut walk = Vec::new();
+        let mut idx = (0..self.len()).find(|&idx| indegree[idx] > 0).unwrap();
+        while step[idx].is_none() {
+            step[idx] = Some(walk.len());
+            walk.push(idx);
+            idx = predecessor[idx].unwrap();
+        }
+        let mut cycle = walk.split_off(step[idx].unwrap());
+        cycle.reverse();
+        self.cycle(cycle)
+    }
+
+    /// Depth-first search, listing nodes in reverse order of finishing. The
+    /// search starts from nodes in the order they were added.
+    pub fn dfs(&self) -> Result<Vec<N>, Cycle<N>> {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum Mark {
+            New,
+            Open,
+            Done,
+        }
+        let mut marks = vec![Mark::New; self.len()];
+        let mut finished = Vec::with_capacity(self.len());
+        for root in 0..self.len() {
+            if marks[root] != Mark::New {
+                continue;
+            }
+            marks[root] = Mark::Open;
+            let mut stack = vec![(root, 0)];
+            while let Some((idx, edge)) = stack.last_mut() {
+                let idx = *idx;
+                let Some(&next) = self.successors[idx].get(*edge) else {
+                    marks[idx] = Mark::Done;
+                    finished.push(idx);
+                    stack.pop();
+                    continue;
+                };
+                *edge += 1;
+                match marks[next] {
+                    Mark::New => {
+                        marks[next] = Mark::Open;
+                        stack.push((next, 0));
+                    }
+                    Mark::Open => {
+                        let start = stack.iter().position(|&(idx, _)| idx == next).unwrap();
+                        let cycle = stack[start..].iter().map(|&(idx, _)| idx).collect();
+                        return Err(self.cycle(cycle));
+                    }
+                    Mark::Done => {}
+                }
+            }
+        }
+        finished.reverse();
+        Ok(self.order(finished))
+    }
+}
+
+impl<N: Clone + Eq + Hash> FromIterator<(N, N)> for Dag<N> {
+    fn from_iter<I: IntoIterator<Item = (N, N)>>(edges: I) -> Self {
+        let mut dag = Dag::new();
+        for (before, after) in edges {
+            dag.add_edge(before, after);
+        }
+        dag
+    }
+}
+
+/// The nodes that are ready to be taken, in [`TieBreak`] order.
+enum Free<'a, N> {
+    Queue(VecDeque<usize>),
+    Heap(&'a [N], BinaryHeap<Reverse<(&'a N, usize)>>),
+}
+
+impl<'a, N: Ord> Free<'a, N> {
+    fn new(dag: &'a Dag<N>, tie_break: TieBreak) -> Self {
+        match tie_break {
+            TieBreak::Insertion => Free::Queue(VecDeque::new()),
+            TieBreak::Lexicographic => Free::Heap(&dag.nodes, BinaryHeap::new()),
+        }
+    }
+
+    fn push(&mut self, idx: usize) {
+        match self {
+            Free::Queue(queue) => queue.push_back(idx),
+            Free::Heap(nodes, heap) => heap.push(Reverse((&nodes[idx], idx))),
+        }
+    }
+
+    fn pop(&mut self) -> Option<usize> {
+        match self {
+            Free::Queue(queue) => queue.pop_front(),
+            Free::Heap(_, heap) => heap.pop().map(|Reverse((_, idx))| idx),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shirts() -> Dag<&'static str> {
+        let mut dag: Dag<_> = [
+            ("undershirt", "shirt"),
+            ("shirt", "tie"),
+            ("tie", "jacket"),
+            ("shirt", "belt"),
+            ("belt", "jacket"),
+        ]
+        .into_iter()
+        .collect();
+        dag.add_node("watch");
+        dag
+    }
+
+    #[test]
+    fn orders_are_deterministic() {
+        let dag = shirts();
+        assert_eq!(
+            dag.kahn(TieBreak::Insertion).unwrap(),
+            ["undershirt", "watch", "shirt", "tie", "belt", "jacket"]
+        );
+        assert_eq!(
+            dag.kahn(TieBreak::Lexicographic).unwrap(),
+            ["undershirt", "shirt", "belt", "tie", "jacket", "watch"]
+        );
+        assert_eq!(
+            dag.dfs().unwrap(),
+            ["watch", "undershirt", "shirt", "belt", "tie", "jacket"]
+        );
+    }
+
+    #[test]
+    fn cycles_are_reported() {
+        let mut dag = shirts();
+        dag.add_edge("jacket", "shirt");
+        let cycle = Cycle(vec!["shirt", "tie", "jacket"]);
+        assert_eq!(dag.kahn(TieBreak::Insertion), Err(cycle.clone()));
+        assert_eq!(dag.dfs(), Err(cycle));
+        let err = Dag::from_iter([(1, 1)]).dfs().unwrap_err();
+        assert_eq!(err.to_string(), "cycle: 1 -> 1");
+    }
+}
@@ -0,0 +1,168 @@
+//! Times parsing and both parts of every day that has a cached input, then
+//! writes a summary table and `summary.json` next to Criterion's own reports.
+//!
+//! ```text
+//! cargo bench -p aoc
+//! cargo bench -p aoc -- 2024-day06
+//! ```
+
+use aoc::inputs::InputCache;
+use aoc::params::Params;
+use aoc::registry::{DAYS, Day};
+use criterion::{Criterion, black_box};
+use serde::{Deserialize, Serialize};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+const STEPS: [&str; 3] = ["parse", "part_one", "part_two"];
+
+fn group_name(day: &Day) -> String {
+    format!("{}-day{:02}", day.year, day.day)
+}
+
+fn bench_days(c: &mut Criterion) {
+    for day in DAYS {
+        let cache = InputCache::default();
+        let Ok(text) = cache.get(day.year, day.day, None) else {
+            continue;
+        };
+        let params = match Params::for_input(&cache.path(day.year, day.day)) {
+            Ok(params) => params,
+            Err(err) => {
+                eprintln!("skipping {}: {err}", group_name(day));
+                continue;
+            }
+        };
+        let input = match day.parse(&text, &params) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("skipping {}: {err}", group_name(day));
+                continue;
+            }
+        };
+        let mut group = c.benchmark_group(group_name(day));
+        group.bench_function(STEPS[0], |b| {
+            b.iter(|| day.parse(black_box(&text), &params))
+        });
+        group.bench_function(STEPS[1], |b| b.iter(|| day.part_one(black_box(&input))));
+        if day.part_two(&input).is_some() {
+            group.bench_function(STEPS[2], |b| b.iter(|| day.part_two(black_box(&input))));
+        }
+        group.finish();
+    }
+}
+
+/// Mirrors how Criterion picks its output directory when run through `cargo bench`.
+fn criterion_dir() -> PathBuf {
+    if let Some(home) = env::var_os("CRITERION_HOME") {
+        PathBuf::from(home)
+    } else if let Some(target) = env::var_os("CARGO_TARGET_DIR") {
+        Path::new(&target).join("criterion")
+    } else {
+        PathBuf::from("target/criterion")
+    }
+}
+
+#[derive(Deserialize)]
+struct Estimates {
+    mean: Estimate,
+    median: Estimate,
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+    point_estimate: f64,
+}
+
+#[derive(Serialize)]
+struct Timing {
+    year: u16,
+    day: u8,
+    step: &'static str,
+    mean_ns: f64,
+    median_ns: f64,
+}
+
+/// Timings measured since `started`, so that days filtered out of this run
+/// don't show up with stale numbers.
+fn collect_timings(dir: &Path, started: SystemTime) -> Vec<Timing> {
+    let mut timings = Vec::new();
+    for day in DAYS {
+        for step in STEPS {
+            let path = dir
+                .join(group_name(day))
+                .join(step)
+                .join("new/estimates.json");
+            let Ok(modified) = fs::metadata(&path).and_then(|metadata| metadata.modified()) else {
+                continue;
+            };
+            if modified < started {
+                continue;
+            }
+            let Some(estimates) = fs::read_to_string(&path)
+                .ok()
+                .and_then(|text| serde_json::from_str::<Estimates>(&text).ok())
+            else {
+                continue;
+            };
+            timings.push(Timing {
+                year: day.year,
+                day: day.day,
+                step,
+                mean_ns: estimates.mean.point_estimate,
+                median_ns: estimates.median.point_estimate,
+            });
+        }
+    }
+    timings
+}
+
+fn format_duration(ns: f64) -> String {
+    match ns {
+        ns if ns < 1e3 => format!("{ns:.1} ns"),
+        ns if ns < 1e6 => format!("{:.1} µs", ns / 1e3),
+        ns if ns < 1e9 => format!("{:.1} ms", ns / 1e6),
+        ns => format!("{:.2} s", ns / 1e9),
+    }
+}
+
+fn main() {
+    // `cargo bench` runs from the package directory, but inputs live at the
+    // workspace root.
+    env::set_current_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("..")).unwrap();
+    let started = SystemTime::now();
+    let mut criterion = Criterion::default().configure_from_args();
+    bench_days(&mut criterion);
+    criterion.final_summary();
+
+    let dir = criterion_dir();
+    let timings = collect_timings(&dir, started);
+    if timings.is_empty() {
+        return;
+    }
+
+    println!(
+        "{:<4}  {:<3}  {:<8}  {:>10}  {:>10}",
+        "year", "day", "step", "mean", "median"
+    );
+    for timing in &timings {
+        println!(
+            "{:<4}  {:<3}  {:<8}  {:>10}  {:>10}",
+            timing.year,
+            format!("{:02}", timing.day),
+            timing.step,
+            format_duration(timing.mean_ns),
+            format_duration(timing.median_ns)
+        );
+    }
+
+    let summary_path = dir.join("summary.json");
+    let json = serde_json::to_string_pretty(&timings).unwrap();
+    match fs::write(&summary_path, json + "\n") {
+        Ok(()) => println!("wrote {}", summary_path.display()),
+        Err(err) => eprintln!("cannot write {}: {err}", summary_path.display()),
+    }
+}
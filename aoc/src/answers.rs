@@ -0,0 +1,169 @@
+use serde::{
+    Deserialize, Deserializer,
+    de::{self, Visitor},
+};
+use std::{collections::HashMap, fmt, fs, io, path::Path};
+
+/// Known-good answers for one year, read from `answers/<year>.toml`:
+///
+/// ```toml
+/// [day01]
+/// part_one = 11
+/// part_two = "31"
+/// ```
+#[derive(Default)]
+pub struct KnownAnswers {
+    answers: HashMap<(u8, Part), String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "one"),
+            Part::Two => write!(f, "two"),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    part_one: Option<Answer>,
+    part_two: Option<Answer>,
+}
+
+/// Numbers may be written bare or quoted; either way they are compared as
+/// text. This isn't `#[serde(untagged)]`, which can't buffer integers wider
+/// than 64 bits.
+struct Answer(String);
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AnswerVisitor;
+
+        impl Visitor<'_> for AnswerVisitor {
+            type Value = Answer;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "an integer or a string")
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Answer, E> {
+                Ok(Answer(value.to_string()))
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Answer, E> {
+                Ok(Answer(value.to_string()))
+            }
+
+            fn visit_i128<E: de::Error>(self, value: i128) -> Result<Answer, E> {
+                Ok(Answer(value.to_string()))
+            }
+
+            fn visit_u128<E: de::Error>(self, value: u128) -> Result<Answer, E> {
+                Ok(Answer(value.to_string()))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Answer, E> {
+                Ok(Answer(value.to_string()))
+            }
+        }
+
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(io::Error),
+    Toml(toml::de::Error),
+    BadDay(String),
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io(err) => write!(f, "{err}"),
+            AnswersError::Toml(err) => write!(f, "{err}"),
+            AnswersError::BadDay(key) => {
+                write!(f, "expected a table like `[day01]`, found `[{key}]`")
+            }
+        }
+    }
+}
+
+impl KnownAnswers {
+    /// A missing file just means that no answers are known yet.
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(AnswersError::Io(err)),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, AnswersError> {
+        let days: HashMap<String, DayAnswers> = toml::from_str(text).map_err(AnswersError::Toml)?;
+        let mut answers = HashMap::new();
+        for (key, day_answers) in days {
+            let day: u8 = key
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| AnswersError::BadDay(key.clone()))?;
+            for (part, answer) in [
+                (Part::One, day_answers.part_one),
+                (Part::Two, day_answers.part_two),
+            ] {
+                if let Some(answer) = answer {
+                    answers.insert((day, part), answer.0);
+                }
+            }
+        }
+        Ok(KnownAnswers { answers })
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_may_be_bare_or_quoted() {
+        let answers = KnownAnswers::parse(
+            "[day01]\npart_one = 11\npart_two = \"31\"\n\n\
+             [day24]\npart_one = 170141183460469231731687303715884105727\n",
+        )
+        .unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("11"));
+        assert_eq!(answers.get(1, Part::Two), Some("31"));
+        assert_eq!(
+            answers.get(24, Part::One),
+            Some(i128::MAX.to_string().as_str())
+        );
+        assert_eq!(answers.get(24, Part::Two), None);
+        assert!(KnownAnswers::parse("[day01]\npart_one = 1.5\n").is_err());
+        assert!(matches!(
+            KnownAnswers::parse("[first]\npart_one = 1\n"),
+            Err(AnswersError::BadDay(_))
+        ));
+    }
+}
@@ -0,0 +1,130 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Where inputs are cached unless told otherwise.
+pub const DEFAULT_DIR: &str = "inputs";
+
+/// Downloads puzzle inputs.
+pub trait Fetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError>;
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// The input is not cached, and there is no fetcher to download it with.
+    NotCached(PathBuf),
+    Io(PathBuf, io::Error),
+    /// The server answered with something other than 200 OK, such as 400 for
+    /// an expired session or 404 for a puzzle that is not unlocked yet.
+    Status(u16),
+    Transport(String),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::NotCached(path) => write!(f, "no input at {}", path.display()),
+            FetchError::Io(path, err) => write!(f, "{}: {err}", path.display()),
+            FetchError::Status(code) => write!(f, "server answered with status {code}"),
+            FetchError::Transport(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// Fetches inputs over HTTP from `<base_url>/<year>/day/<day>/input`,
+/// authenticating with a session cookie.
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl HttpFetcher {
+    pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        HttpFetcher {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent("github.com/ParthKohli/advent-of-code-rs")
+                .build(),
+        }
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|err| FetchError::Transport(err.to_string())),
+            Err(ureq::Error::Status(code, _)) => Err(FetchError::Status(code)),
+            Err(err) => Err(FetchError::Transport(err.to_string())),
+        }
+    }
+}
+
+/// Inputs stored as `<dir>/<year>/day<NN>.txt`, fetched on first use.
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl Default for InputCache {
+    fn default() -> Self {
+        InputCache::new(DEFAULT_DIR)
+    }
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        InputCache { dir: dir.into() }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{day:02}.txt"))
+    }
+
+    /// Reads the cached input, downloading and storing it first if it is
+    /// missing and there is a `fetcher`.
+    pub fn get(
+        &self,
+        year: u16,
+        day: u8,
+        fetcher: Option<&dyn Fetcher>,
+    ) -> Result<String, FetchError> {
+        let path = self.path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(input) => Ok(input),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => match fetcher {
+                Some(fetcher) => {
+                    let input = fetcher.fetch(year, day)?;
+                    store(&path, &input)?;
+                    Ok(input)
+                }
+                None => Err(FetchError::NotCached(path)),
+            },
+            Err(err) => Err(FetchError::Io(path, err)),
+        }
+    }
+}
+
+fn store(path: &Path, input: &str) -> Result<(), FetchError> {
+    let io_error = |err| FetchError::Io(path.to_path_buf(), err);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    fs::write(path, input).map_err(io_error)
+}
@@ -0,0 +1,5 @@
+pub mod answers;
+pub mod inputs;
+pub mod params;
+pub mod registry;
+pub mod solution;
@@ -0,0 +1,342 @@
+use aoc::answers::{KnownAnswers, Part};
+use aoc::inputs::{self, FetchError, Fetcher, HttpFetcher, InputCache};
+use aoc::params::Params;
+use aoc::registry::{self, Day};
+use aoc::solution::SolveError;
+use aoc_common::Answer;
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Where inputs are cached, as `<dir>/<year>/day<NN>.txt`. Missing inputs
+    /// are downloaded if `AOC_SESSION` holds a session cookie.
+    #[arg(long, global = true, default_value = inputs::DEFAULT_DIR)]
+    inputs: PathBuf,
+}
+
+/// The cached inputs, plus a way of downloading missing ones if the
+/// environment provides a session.
+struct Inputs {
+    cache: InputCache,
+    fetcher: Option<HttpFetcher>,
+}
+
+impl Inputs {
+    fn new(dir: PathBuf) -> Self {
+        let fetcher = env::var("AOC_SESSION").ok().map(|session| {
+            let base_url = env::var("AOC_BASE_URL")
+                .unwrap_or_else(|_| HttpFetcher::DEFAULT_BASE_URL.to_string());
+            HttpFetcher::new(base_url, session)
+        });
+        Inputs {
+            cache: InputCache::new(dir),
+            fetcher,
+        }
+    }
+
+    fn get(&self, entry: &Day) -> Result<String, FetchError> {
+        let fetcher = self.fetcher.as_ref().map(|fetcher| fetcher as &dyn Fetcher);
+        self.cache.get(entry.year, entry.day, fetcher)
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs every day, a whole year, or a single day.
+    Run {
+        year: Option<u16>,
+        day: Option<u8>,
+        /// Input file for a single day, instead of the cached one.
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+        /// Overrides a parameter of a single day, such as `--param dims=[7,7]`.
+        /// Applied on top of the input's `.toml` sidecar, if it has one.
+        #[arg(long = "param", value_name = "KEY=VALUE", requires = "day")]
+        params: Vec<String>,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Checks answers against `<answers>/<year>.toml`, using the cached inputs.
+    Verify {
+        year: Option<u16>,
+        day: Option<u8>,
+        /// Where the known answers are. Defaults to the workspace's `answers`
+        /// directory, wherever this is run from.
+        #[arg(long, default_value = ANSWERS_DIR)]
+        answers: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Answers grouped by day, for reading.
+    Text,
+    /// One JSON object per line and part.
+    Json,
+    /// Tab-separated values with a header row.
+    Tsv,
+}
+
+/// One part's answer, as emitted by the machine-readable formats.
+#[derive(Serialize)]
+struct Record {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: String,
+    elapsed_ns: u128,
+}
+
+impl Format {
+    fn begin(self) {
+        if let Format::Tsv = self {
+            println!("year\tday\tpart\tanswer\telapsed_ns");
+        }
+    }
+
+    fn begin_day(self, entry: &Day) {
+        if let Format::Text = self {
+            println!("{} day {:02}", entry.year, entry.day);
+        }
+    }
+
+    fn answer(self, entry: &Day, part: Part, answer: &Answer, elapsed: Duration) {
+        let record = Record {
+            year: entry.year,
+            day: entry.day,
+            part: part.number(),
+            answer: answer.to_string(),
+            elapsed_ns: elapsed.as_nanos(),
+        };
+        match self {
+            Format::Text => println!("  part {part}: {answer}"),
+            Format::Json => println!("{}", serde_json::to_string(&record).unwrap()),
+            Format::Tsv => println!(
+                "{}\t{}\t{}\t{}\t{}",
+                record.year, record.day, record.part, record.answer, record.elapsed_ns
+            ),
+        }
+    }
+}
+
+fn run(
+    inputs: &Inputs,
+    year: Option<u16>,
+    day: Option<u8>,
+    input: Option<PathBuf>,
+    assignments: Vec<String>,
+    format: Format,
+) -> ExitCode {
+    let days = registry::select(year, day);
+    if days.is_empty() {
+        eprintln!("No solutions found");
+        return ExitCode::FAILURE;
+    }
+    let mut overrides = Params::default();
+    for assignment in &assignments {
+        if let Err(err) = overrides.set(assignment) {
+            eprintln!("error: --param {assignment}: {err}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let mut status = ExitCode::SUCCESS;
+    format.begin();
+    for entry in days {
+        format.begin_day(entry);
+        let path = match &input {
+            Some(path) => path.clone(),
+            None => inputs.cache.path(entry.year, entry.day),
+        };
+        let text = match &input {
+            Some(path) => fs::read_to_string(path).map_err(|err| FetchError::Io(path.clone(), err)),
+            None => inputs.get(entry),
+        };
+        let text = match text {
+            Ok(text) => text,
+            Err(err) => {
+                eprintln!("cannot read input: {err}");
+                if day.is_some() {
+                    status = ExitCode::FAILURE;
+                }
+                continue;
+            }
+        };
+        let params = match Params::for_input(&path) {
+            Ok(mut params) => {
+                params.extend(overrides.clone());
+                params
+            }
+            Err(err) => {
+                eprintln!("error: {err}");
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        let parsed = match entry.parse(&text, &params) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                eprintln!("error: {}: {err}", path.display());
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        let start = Instant::now();
+        let part_one = Some(entry.part_one(&parsed));
+        let part_one_elapsed = start.elapsed();
+        let start = Instant::now();
+        let part_two = entry.part_two(&parsed);
+        let part_two_elapsed = start.elapsed();
+        for (part, answer, elapsed) in [
+            (Part::One, part_one, part_one_elapsed),
+            (Part::Two, part_two, part_two_elapsed),
+        ] {
+            match answer {
+                Some(Ok(answer)) => format.answer(entry, part, &answer, elapsed),
+                Some(Err(err)) => {
+                    eprintln!("error: {}: part {part}: {err}", path.display());
+                    status = ExitCode::FAILURE;
+                }
+                None => {}
+            }
+        }
+    }
+    status
+}
+
+const ANSWERS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers");
+
+fn verify(inputs: &Inputs, year: Option<u16>, day: Option<u8>, answers: &Path) -> ExitCode {
+    let days = registry::select(year, day);
+    if days.is_empty() {
+        eprintln!("No solutions found");
+        return ExitCode::FAILURE;
+    }
+
+    let mut known_answers: HashMap<u16, KnownAnswers> = HashMap::new();
+    for entry in &days {
+        if known_answers.contains_key(&entry.year) {
+            continue;
+        }
+        let path = answers.join(format!("{}.toml", entry.year));
+        match KnownAnswers::load(&path) {
+            Ok(answers) => known_answers.insert(entry.year, answers),
+            Err(err) => {
+                eprintln!("error: {}: {err}", path.display());
+                return ExitCode::FAILURE;
+            }
+        };
+    }
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    println!(
+        "{:<4}  {:<3}  {:<4}  {:<7}  detail",
+        "year", "day", "part", "status"
+    );
+    for entry in days {
+        let row = |part: &str, status: &str, detail: &str| {
+            let day = format!("{:02}", entry.day);
+            let line = format!(
+                "{:<4}  {day:<3}  {part:<4}  {status:<7}  {detail}",
+                entry.year
+            );
+            println!("{}", line.trim_end());
+        };
+        let text = match inputs.get(entry) {
+            Ok(text) => text,
+            Err(err @ FetchError::NotCached(_)) => {
+                missing += 1;
+                row("-", "missing", &err.to_string());
+                continue;
+            }
+            Err(err) => {
+                failed += 1;
+                row("-", "fail", &err.to_string());
+                continue;
+            }
+        };
+        let path = inputs.cache.path(entry.year, entry.day);
+        let answers = Params::for_input(&path)
+            .map_err(SolveError::Params)
+            .and_then(|params| entry.solve(&text, &params));
+        let answers = match answers {
+            Ok(answers) => answers,
+            Err(err) => {
+                failed += 1;
+                row("-", "fail", &err.to_string());
+                continue;
+            }
+        };
+        for (part, actual) in [
+            (Part::One, Some(answers.part_one)),
+            (Part::Two, answers.part_two),
+        ] {
+            let part_name = part.to_string();
+            let actual = match actual {
+                Some(Ok(actual)) => actual.to_string(),
+                Some(Err(err)) => {
+                    failed += 1;
+                    row(&part_name, "fail", &err.to_string());
+                    continue;
+                }
+                None => continue,
+            };
+            match known_answers[&entry.year].get(entry.day, part) {
+                Some(expected) if expected == actual => {
+                    passed += 1;
+                    row(&part_name, "pass", "");
+                }
+                Some(expected) => {
+                    failed += 1;
+                    row(
+                        &part_name,
+                        "fail",
+                        &format!("expected {expected}, got {actual}"),
+                    );
+                }
+                None => {
+                    missing += 1;
+                    row(
+                        &part_name,
+                        "missing",
+                        &format!("no known answer, got {actual}"),
+                    );
+                }
+            }
+        }
+    }
+    println!("{passed} passed, {failed} failed, {missing} missing");
+
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let inputs = Inputs::new(cli.inputs);
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            input,
+            params,
+            format,
+        } => run(&inputs, year, day, input, params, format),
+        Command::Verify { year, day, answers } => verify(&inputs, year, day, &answers),
+    }
+}
@@ -0,0 +1,120 @@
+use serde::de::DeserializeOwned;
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Overrides for a day's parameters, written as TOML:
+///
+/// ```toml
+/// dims = [7, 7]
+/// prefix_size = 12
+/// ```
+///
+/// They come from a sidecar file next to the input, such as
+/// `inputs/2024/day18.toml`, and from `--param` flags.
+#[derive(Clone, Debug, Default)]
+pub struct Params(toml::Table);
+
+#[derive(Debug)]
+pub enum ParamsError {
+    Io(PathBuf, io::Error),
+    Toml(toml::de::Error),
+}
+
+impl fmt::Display for ParamsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamsError::Io(path, err) => write!(f, "{}: {err}", path.display()),
+            ParamsError::Toml(err) => {
+                write!(f, "invalid parameters: {}", err.to_string().trim_end())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParamsError {}
+
+impl Params {
+    /// A missing file just means that nothing is overridden.
+    pub fn load(path: &Path) -> Result<Self, ParamsError> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(ParamsError::Io(path.to_path_buf(), err)),
+        }
+    }
+
+    /// Loads the sidecar of the input at `input`, which has the same name
+    /// but a `.toml` extension.
+    pub fn for_input(input: &Path) -> Result<Self, ParamsError> {
+        Self::load(&input.with_extension("toml"))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, ParamsError> {
+        text.parse().map(Params).map_err(ParamsError::Toml)
+    }
+
+    /// Applies `key=value` on top, where the value is written as in TOML.
+    pub fn set(&mut self, assignment: &str) -> Result<(), ParamsError> {
+        self.extend(Self::parse(assignment)?);
+        Ok(())
+    }
+
+    /// Applies every parameter of `other` on top of these.
+    pub fn extend(&mut self, other: Params) {
+        self.0.extend(other.0);
+    }
+
+    pub(crate) fn decode<T: DeserializeOwned>(&self) -> Result<T, ParamsError> {
+        toml::Value::Table(self.0.clone())
+            .try_into()
+            .map_err(ParamsError::Toml)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    #[serde(default, deny_unknown_fields)]
+    struct Example {
+        dims: (i32, i32),
+        prefix_size: usize,
+    }
+
+    impl Default for Example {
+        fn default() -> Self {
+            Example {
+                dims: (71, 71),
+                prefix_size: 1024,
+            }
+        }
+    }
+
+    #[test]
+    fn later_overrides_win_and_the_rest_default() {
+        let mut params = Params::parse("dims = [7, 7]\nprefix_size = 12").unwrap();
+        params.set("prefix_size=20").unwrap();
+        let example: Example = params.decode().unwrap();
+        assert_eq!(
+            example,
+            Example {
+                dims: (7, 7),
+                prefix_size: 20
+            }
+        );
+        assert_eq!(
+            Params::default().decode::<Example>().unwrap(),
+            Example::default()
+        );
+    }
+
+    #[test]
+    fn unknown_parameters_are_rejected() {
+        let params = Params::parse("size = 7").unwrap();
+        assert!(params.decode::<Example>().is_err());
+    }
+}
@@ -0,0 +1,220 @@
+use crate::params::Params;
+use crate::solution::{self, Answers, NoParams, ParsedInput, Solution, SolveError};
+use aoc_common::{Answer, PartError};
+
+/// Adapts a day crate exposing `parse_input`, `part_one` and `part_two` to [`Solution`].
+/// Days whose parts take a `&Params` as well say so with `params = Params`.
+macro_rules! solution {
+    ($name:ident => $krate:ident, $input:ty) => {
+        pub struct $name;
+
+        impl Solution for $name {
+            type Input = $input;
+            type Params = NoParams;
+
+            fn parse(input: &str) -> Result<Self::Input, aoc_common::ParseError> {
+                $krate::parse_input(input)
+            }
+
+            fn part_one(
+                input: &Self::Input,
+                _params: &NoParams,
+            ) -> Result<aoc_common::Answer, aoc_common::PartError> {
+                aoc_common::IntoAnswer::into_answer($krate::part_one(input))
+            }
+
+            fn part_two(
+                input: &Self::Input,
+                _params: &NoParams,
+            ) -> Option<Result<aoc_common::Answer, aoc_common::PartError>> {
+                Some(aoc_common::IntoAnswer::into_answer($krate::part_two(input)))
+            }
+        }
+    };
+    ($name:ident => $krate:ident, $input:ty, params = $params:ty) => {
+        pub struct $name;
+
+        impl Solution for $name {
+            type Input = $input;
+            type Params = $params;
+
+            fn parse(input: &str) -> Result<Self::Input, aoc_common::ParseError> {
+                $krate::parse_input(input)
+            }
+
+            fn part_one(
+                input: &Self::Input,
+                params: &$params,
+            ) -> Result<aoc_common::Answer, aoc_common::PartError> {
+                aoc_common::IntoAnswer::into_answer($krate::part_one(input, params))
+            }
+
+            fn part_two(
+                input: &Self::Input,
+                params: &$params,
+            ) -> Option<Result<aoc_common::Answer, aoc_common::PartError>> {
+                Some(aoc_common::IntoAnswer::into_answer($krate::part_two(
+                    input, params,
+                )))
+            }
+        }
+    };
+    ($name:ident => $krate:ident, $input:ty, part_one_only) => {
+        pub struct $name;
+
+        impl Solution for $name {
+            type Input = $input;
+            type Params = NoParams;
+
+            fn parse(input: &str) -> Result<Self::Input, aoc_common::ParseError> {
+                $krate::parse_input(input)
+            }
+
+            fn part_one(
+                input: &Self::Input,
+                _params: &NoParams,
+            ) -> Result<aoc_common::Answer, aoc_common::PartError> {
+                aoc_common::IntoAnswer::into_answer($krate::part_one(input))
+            }
+
+            fn part_two(
+                _input: &Self::Input,
+                _params: &NoParams,
+            ) -> Option<Result<aoc_common::Answer, aoc_common::PartError>> {
+                None
+            }
+        }
+    };
+}
+
+pub mod y2024 {
+    use super::{NoParams, Solution};
+
+    solution!(Day01 => aoc_2024_day01, aoc_2024_day01::Input);
+    solution!(Day02 => aoc_2024_day02, Vec<Vec<i32>>);
+    solution!(Day03 => aoc_2024_day03, Vec<aoc_2024_day03::Instruction>);
+    solution!(Day04 => aoc_2024_day04, aoc_common::Grid<char>);
+    solution!(Day05 => aoc_2024_day05, aoc_2024_day05::Input);
+    solution!(Day06 => aoc_2024_day06, aoc_2024_day06::GridWalk);
+    solution!(Day07 => aoc_2024_day07, Vec<aoc_2024_day07::Equation>);
+    solution!(Day08 => aoc_2024_day08, aoc_common::Grid<char>);
+    solution!(Day09 => aoc_2024_day09, String);
+    solution!(Day10 => aoc_2024_day10, aoc_common::Grid<u32>);
+    solution!(Day11 => aoc_2024_day11, Vec<u64>);
+    solution!(Day12 => aoc_2024_day12, aoc_common::Grid<char>);
+    solution!(Day13 => aoc_2024_day13, Vec<aoc_2024_day13::Machine>);
+    solution!(Day14 => aoc_2024_day14, aoc_2024_day14::Grid, params = aoc_2024_day14::Params);
+    solution!(Day15 => aoc_2024_day15, aoc_2024_day15::GridWalk);
+    solution!(Day16 => aoc_2024_day16, aoc_2024_day16::ShortestPaths);
+    solution!(Day17 => aoc_2024_day17, aoc_2024_day17::VM);
+    solution!(Day18 => aoc_2024_day18, Vec<(i32, i32)>, params = aoc_2024_day18::Params);
+    solution!(Day19 => aoc_2024_day19, aoc_2024_day19::Input);
+    solution!(Day20 => aoc_2024_day20, aoc_2024_day20::Racetrack, params = aoc_2024_day20::Params);
+    solution!(Day21 => aoc_2024_day21, Vec<String>);
+    solution!(Day22 => aoc_2024_day22, Vec<u64>);
+    solution!(Day23 => aoc_2024_day23, aoc_2024_day23::Network);
+    solution!(Day24 => aoc_2024_day24, aoc_2024_day24::Network);
+    solution!(Day25 => aoc_2024_day25, aoc_2024_day25::LocksAndKeys, part_one_only);
+}
+
+pub mod y2025 {
+    use super::{NoParams, Solution};
+
+    solution!(Day01 => aoc_2025_day01, Vec<i32>);
+    solution!(Day02 => aoc_2025_day02, aoc_2025_day02::Ranges);
+    solution!(Day03 => aoc_2025_day03, Vec<Vec<u64>>);
+    solution!(Day04 => aoc_2025_day04, aoc_common::Grid<char>);
+    solution!(Day05 => aoc_2025_day05, aoc_2025_day05::Input);
+    solution!(Day06 => aoc_2025_day06, aoc_2025_day06::Worksheet);
+    solution!(Day07 => aoc_2025_day07, aoc_common::Grid<char>);
+    solution!(Day08 => aoc_2025_day08, Vec<(i64, i64, i64)>, params = aoc_2025_day08::Params);
+    solution!(Day11 => aoc_2025_day11, aoc_2025_day11::Graph);
+}
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    parse: fn(&str, &Params) -> Result<ParsedInput, SolveError>,
+    part_one: fn(&ParsedInput) -> Result<Answer, PartError>,
+    part_two: fn(&ParsedInput) -> Option<Result<Answer, PartError>>,
+}
+
+impl Day {
+    const fn new<S: Solution>(year: u16, day: u8) -> Self {
+        Day {
+            year,
+            day,
+            parse: solution::parse::<S>,
+            part_one: solution::part_one::<S>,
+            part_two: solution::part_two::<S>,
+        }
+    }
+
+    /// Parses `input`, and fills in this day's parameters from `params`.
+    pub fn parse(&self, input: &str, params: &Params) -> Result<ParsedInput, SolveError> {
+        (self.parse)(input, params)
+    }
+
+    /// `input` must come from this day's [`Day::parse`].
+    pub fn part_one(&self, input: &ParsedInput) -> Result<Answer, PartError> {
+        (self.part_one)(input)
+    }
+
+    /// `input` must come from this day's [`Day::parse`].
+    pub fn part_two(&self, input: &ParsedInput) -> Option<Result<Answer, PartError>> {
+        (self.part_two)(input)
+    }
+
+    pub fn solve(&self, input: &str, params: &Params) -> Result<Answers, SolveError> {
+        let input = self.parse(input, params)?;
+        Ok(Answers {
+            part_one: self.part_one(&input),
+            part_two: self.part_two(&input),
+        })
+    }
+}
+
+pub static DAYS: &[Day] = &[
+    Day::new::<y2024::Day01>(2024, 1),
+    Day::new::<y2024::Day02>(2024, 2),
+    Day::new::<y2024::Day03>(2024, 3),
+    Day::new::<y2024::Day04>(2024, 4),
+    Day::new::<y2024::Day05>(2024, 5),
+    Day::new::<y2024::Day06>(2024, 6),
+    Day::new::<y2024::Day07>(2024, 7),
+    Day::new::<y2024::Day08>(2024, 8),
+    Day::new::<y2024::Day09>(2024, 9),
+    Day::new::<y2024::Day10>(2024, 10),
+    Day::new::<y2024::Day11>(2024, 11),
+    Day::new::<y2024::Day12>(2024, 12),
+    Day::new::<y2024::Day13>(2024, 13),
+    Day::new::<y2024::Day14>(2024, 14),
+    Day::new::<y2024::Day15>(2024, 15),
+    Day::new::<y2024::Day16>(2024, 16),
+    Day::new::<y2024::Day17>(2024, 17),
+    Day::new::<y2024::Day18>(2024, 18),
+    Day::new::<y2024::Day19>(2024, 19),
+    Day::new::<y2024::Day20>(2024, 20),
+    Day::new::<y2024::Day21>(2024, 21),
+    Day::new::<y2024::Day22>(2024, 22),
+    Day::new::<y2024::Day23>(2024, 23),
+    Day::new::<y2024::Day24>(2024, 24),
+    Day::new::<y2024::Day25>(2024, 25),
+    Day::new::<y2025::Day01>(2025, 1),
+    Day::new::<y2025::Day02>(2025, 2),
+    Day::new::<y2025::Day03>(2025, 3),
+    Day::new::<y2025::Day04>(2025, 4),
+    Day::new::<y2025::Day05>(2025, 5),
+    Day::new::<y2025::Day06>(2025, 6),
+    Day::new::<y2025::Day07>(2025, 7),
+    Day::new::<y2025::Day08>(2025, 8),
+    Day::new::<y2025::Day11>(2025, 11),
+];
+
+/// Every registered day in `year` (or in all years), optionally narrowed down to one day.
+pub fn select(year: Option<u16>, day: Option<u8>) -> Vec<&'static Day> {
+    DAYS.iter()
+        .filter(|entry| year.is_none_or(|year| entry.year == year))
+        .filter(|entry| day.is_none_or(|day| entry.day == day))
+        .collect()
+}
@@ -0,0 +1,74 @@
+use crate::params::{Params, ParamsError};
+use aoc_common::{Answer, ParseError, PartError};
+use serde::{Deserialize, de::DeserializeOwned};
+use std::{any::Any, fmt};
+
+/// One day's puzzle. The input is parsed once and shared by both parts.
+pub trait Solution {
+    type Input: 'static;
+
+    /// Values such as grid sizes that differ between the examples and real
+    /// inputs. Fields that aren't overridden keep their defaults.
+    type Params: DeserializeOwned + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(input: &Self::Input, params: &Self::Params) -> Result<Answer, PartError>;
+
+    /// `None` for days without a second part, such as the last day of a year.
+    fn part_two(input: &Self::Input, params: &Self::Params) -> Option<Result<Answer, PartError>>;
+}
+
+/// The parameters of days that don't have any, so that setting one is an error.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoParams {}
+
+pub struct Answers {
+    pub part_one: Result<Answer, PartError>,
+    pub part_two: Option<Result<Answer, PartError>>,
+}
+
+#[derive(Debug)]
+pub enum SolveError {
+    Params(ParamsError),
+    Parse(ParseError),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Params(err) => write!(f, "{err}"),
+            SolveError::Parse(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// A parsed input whose type is only known to the [`Solution`] that parsed it.
+pub struct ParsedInput(Box<dyn Any>);
+
+impl ParsedInput {
+    fn get<S: Solution>(&self) -> &(S::Input, S::Params) {
+        self.0
+            .downcast_ref()
+            .expect("input was parsed by a different day")
+    }
+}
+
+pub(crate) fn parse<S: Solution>(input: &str, params: &Params) -> Result<ParsedInput, SolveError> {
+    let params: S::Params = params.decode().map_err(SolveError::Params)?;
+    let input = S::parse(input).map_err(SolveError::Parse)?;
+    Ok(ParsedInput(Box::new((input, params))))
+}
+
+pub(crate) fn part_one<S: Solution>(input: &ParsedInput) -> Result<Answer, PartError> {
+    let (input, params) = input.get::<S>();
+    S::part_one(input, params)
+}
+
+pub(crate) fn part_two<S: Solution>(input: &ParsedInput) -> Option<Result<Answer, PartError>> {
+    let (input, params) = input.get::<S>();
+    S::part_two(input, params)
+}
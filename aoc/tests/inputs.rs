@@ -0,0 +1,132 @@
+use aoc::inputs::{FetchError, Fetcher, HttpFetcher, InputCache};
+use aoc::params::Params;
+use aoc::registry;
+use std::{env, fs, path::PathBuf, process, thread};
+use tiny_http::{Response, Server};
+
+const SESSION: &str = "fixture-session";
+
+/// Stands in for the puzzle server: serves `tests/fixtures/<year>/day<NN>.txt`
+/// to requests carrying the right session cookie.
+fn serve_fixtures() -> String {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+    thread::spawn(move || {
+        for request in server.incoming_requests() {
+            let authorized = request.headers().iter().any(|header| {
+                header.field.equiv("Cookie")
+                    && header.value.as_str() == format!("session={SESSION}")
+            });
+            let fixture = fixture_path(request.url());
+            let response = match fixture.and_then(|path| fs::read_to_string(path).ok()) {
+                _ if !authorized => {
+                    Response::from_string("Puzzle inputs differ by user.").with_status_code(400)
+                }
+                Some(input) => Response::from_string(input),
+                None => Response::from_string("Not found").with_status_code(404),
+            };
+            request.respond(response).unwrap();
+        }
+    });
+    url
+}
+
+/// Maps `/<year>/day/<day>/input` to its fixture.
+fn fixture_path(url: &str) -> Option<PathBuf> {
+    let mut parts = url.trim_start_matches('/').split('/');
+    let (Some(year), Some("day"), Some(day), Some("input"), None) = (
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+    ) else {
+        return None;
+    };
+    let day: u8 = day.parse().ok()?;
+    Some(
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(year)
+            .join(format!("day{day:02}.txt")),
+    )
+}
+
+fn temp_cache(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-inputs-{}-{name}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+/// Fails the test if the cache asks it for anything.
+struct Unreachable;
+
+impl Fetcher for Unreachable {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        panic!("{year} day {day} should have been cached");
+    }
+}
+
+#[test]
+fn fetches_fixture_with_session() {
+    let fetcher = HttpFetcher::new(serve_fixtures(), SESSION);
+    let input = fetcher.fetch(2024, 1).unwrap();
+    assert_eq!(input, include_str!("fixtures/2024/day01.txt"));
+}
+
+#[test]
+fn reports_status_of_failed_fetches() {
+    let url = serve_fixtures();
+    let wrong_session = HttpFetcher::new(url.clone(), "expired");
+    assert!(matches!(
+        wrong_session.fetch(2024, 1),
+        Err(FetchError::Status(400))
+    ));
+    let fetcher = HttpFetcher::new(url, SESSION);
+    assert!(matches!(
+        fetcher.fetch(2024, 2),
+        Err(FetchError::Status(404))
+    ));
+}
+
+#[test]
+fn cache_fetches_once_then_reads_from_disk() {
+    let dir = temp_cache("fetch-once");
+    let cache = InputCache::new(&dir);
+    let fetcher = HttpFetcher::new(serve_fixtures(), SESSION);
+
+    let fetched = cache.get(2024, 1, Some(&fetcher)).unwrap();
+    assert_eq!(
+        fs::read_to_string(dir.join("2024/day01.txt")).unwrap(),
+        fetched
+    );
+    assert_eq!(cache.get(2024, 1, Some(&Unreachable)).unwrap(), fetched);
+    assert_eq!(cache.get(2024, 1, None).unwrap(), fetched);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn cache_without_fetcher_reports_missing_input() {
+    let dir = temp_cache("missing");
+    let cache = InputCache::new(&dir);
+    match cache.get(2024, 1, None) {
+        Err(FetchError::NotCached(path)) => assert_eq!(path, dir.join("2024/day01.txt")),
+        other => panic!("expected a missing input, got {other:?}"),
+    }
+}
+
+#[test]
+fn solves_by_year_and_day() {
+    let dir = temp_cache("solve");
+    let cache = InputCache::new(&dir);
+    let fetcher = HttpFetcher::new(serve_fixtures(), SESSION);
+    let day = registry::select(Some(2024), Some(1))[0];
+
+    let input = cache.get(day.year, day.day, Some(&fetcher)).unwrap();
+    let answers = day.solve(&input, &Params::default()).unwrap();
+    assert_eq!(answers.part_one.unwrap().to_string(), "11");
+    assert_eq!(answers.part_two.unwrap().unwrap().to_string(), "31");
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,66 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{self, Command, Output},
+};
+
+fn fixtures() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+/// Runs `aoc verify` on the example inputs in `tests/fixtures`, from
+/// somewhere other than the workspace.
+fn verify(answers: &Path, args: &[&str]) -> (Output, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("verify")
+        .args(args)
+        .arg("--inputs")
+        .arg(fixtures())
+        .arg("--answers")
+        .arg(answers)
+        .current_dir(env::temp_dir())
+        .env_remove("AOC_SESSION")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout.clone()).unwrap();
+    (output, stdout)
+}
+
+#[test]
+fn examples_pass() {
+    let (output, stdout) = verify(&fixtures().join("answers"), &["2024", "18"]);
+    assert!(output.status.success(), "{stdout}");
+    assert!(stdout.contains("2024  18   one   pass\n"));
+    assert!(stdout.contains("2024  18   two   pass\n"));
+    assert!(stdout.ends_with("2 passed, 0 failed, 0 missing\n"));
+
+    let (output, stdout) = verify(&fixtures().join("answers"), &["2025"]);
+    assert!(output.status.success(), "{stdout}");
+    assert!(stdout.contains("4 passed, 0 failed, "));
+    assert!(stdout.contains("2025  02   -     missing  no input at "));
+}
+
+#[test]
+fn wrong_and_unknown_answers_are_reported() {
+    let answers = env::temp_dir().join(format!("aoc-answers-{}", process::id()));
+    fs::create_dir_all(&answers).unwrap();
+    fs::write(answers.join("2024.toml"), "[day01]\npart_one = 12\n").unwrap();
+    let (output, stdout) = verify(&answers, &["2024", "1"]);
+    fs::remove_dir_all(&answers).unwrap();
+    assert!(!output.status.success());
+    assert!(stdout.contains("2024  01   one   fail     expected 12, got 11\n"));
+    assert!(stdout.contains("2024  01   two   missing  no known answer, got 31\n"));
+    assert!(stdout.ends_with("0 passed, 1 failed, 1 missing\n"));
+}
+
+#[test]
+fn malformed_answers_are_errors() {
+    let answers = env::temp_dir().join(format!("aoc-bad-answers-{}", process::id()));
+    fs::create_dir_all(&answers).unwrap();
+    fs::write(answers.join("2024.toml"), "[first]\npart_one = 1\n").unwrap();
+    let (output, _) = verify(&answers, &["2024", "1"]);
+    fs::remove_dir_all(&answers).unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("expected a table like `[day01]`, found `[first]`"));
+}